@@ -0,0 +1,201 @@
+//! `#[derive(AsSlice, AsMutSlice)]` for the `as-slice` crate
+//!
+//! Don't use this crate directly; enable the `derive` feature of `as-slice` instead.
+//!
+//! The derived implementations delegate to one field of a struct. That field is
+//!
+//! - the only field of the struct, for structs with a single field, or
+//! - the field marked with `#[as_slice]`, for structs with several fields.
+//!
+//! ```
+//! use as_slice::{AsMutSlice, AsSlice};
+//!
+//! #[derive(AsSlice, AsMutSlice)]
+//! struct TxBuf([u8; 256]);
+//!
+//! #[derive(AsSlice)]
+//! struct Frame {
+//!     #[as_slice]
+//!     data: [u8; 64],
+//!     len: u8,
+//! }
+//!
+//! let mut tx = TxBuf([0; 256]);
+//! tx.as_mut_slice()[0] = 1;
+//! assert_eq!(tx.as_slice()[0], 1);
+//!
+//! let frame = Frame { data: [0; 64], len: 0 };
+//! assert_eq!(frame.as_slice().len(), 64);
+//! ```
+//!
+//! Structs with several fields and no (or more than one) `#[as_slice]` field are rejected
+//!
+//! ```compile_fail
+//! use as_slice::AsSlice;
+//!
+//! #[derive(AsSlice)]
+//! struct Frame {
+//!     data: [u8; 64],
+//!     crc: [u8; 4],
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use as_slice::AsSlice;
+//!
+//! #[derive(AsSlice)]
+//! struct Frame {
+//!     #[as_slice]
+//!     data: [u8; 64],
+//!     #[as_slice]
+//!     crc: [u8; 4],
+//! }
+//! ```
+//!
+//! as are enums and unions
+//!
+//! ```compile_fail
+//! use as_slice::AsSlice;
+//!
+//! #[derive(AsSlice)]
+//! enum Buffer {
+//!     Small([u8; 16]),
+//!     Large([u8; 256]),
+//! }
+//! ```
+
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Field, Index, Member};
+
+/// Derives `AsSlice` by delegating to the only, or the `#[as_slice]`, field of a struct
+#[proc_macro_derive(AsSlice, attributes(as_slice))]
+pub fn as_slice(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_as_slice(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `AsMutSlice` by delegating to the only, or the `#[as_slice]`, field of a struct
+#[proc_macro_derive(AsMutSlice, attributes(as_slice))]
+pub fn as_mut_slice(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_as_mut_slice(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_as_slice(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, field) = delegate(&input)?;
+    let ty = field.ty.clone();
+
+    input
+        .generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#ty: ::as_slice::AsSlice));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::as_slice::AsSlice for #name #ty_generics #where_clause {
+            type Element = <#ty as ::as_slice::AsSlice>::Element;
+
+            fn as_slice(&self) -> &[Self::Element] {
+                ::as_slice::AsSlice::as_slice(&self.#member)
+            }
+        }
+    })
+}
+
+fn expand_as_mut_slice(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, field) = delegate(&input)?;
+    let ty = field.ty.clone();
+
+    input
+        .generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#ty: ::as_slice::AsMutSlice));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::as_slice::AsMutSlice for #name #ty_generics #where_clause {
+            fn as_mut_slice(&mut self) -> &mut [Self::Element] {
+                ::as_slice::AsMutSlice::as_mut_slice(&mut self.#member)
+            }
+        }
+    })
+}
+
+/// Returns the field the implementation delegates to
+fn delegate(input: &DeriveInput) -> syn::Result<(Member, Field)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new_spanned(
+                data.enum_token,
+                "`AsSlice` and `AsMutSlice` can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "`AsSlice` and `AsMutSlice` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut candidates = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| fields.len() == 1 || is_marked(field));
+
+    let (i, field) = match (candidates.next(), candidates.next()) {
+        (Some(candidate), None) => candidate,
+        (None, _) if fields.is_empty() => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`AsSlice` and `AsMutSlice` can't be derived for structs without fields",
+            ))
+        }
+        (None, _) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ambiguous field: mark the field to delegate to with `#[as_slice]`",
+            ))
+        }
+        (Some(_), Some((_, field))) => {
+            return Err(Error::new_spanned(
+                field,
+                "only one field can be marked with `#[as_slice]`",
+            ))
+        }
+    };
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(i)),
+    };
+
+    Ok((member, field.clone()))
+}
+
+fn is_marked(field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("as_slice"))
+}
@@ -0,0 +1,157 @@
+//! Fixed size array views
+
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use {AsMutSlice, AsSlice};
+
+/// Something that can be seen as an immutable array of `N` elements
+pub trait AsArray<const N: usize>: AsSlice {
+    /// Returns the immutable array view of `Self`
+    fn as_array(&self) -> &[Self::Element; N];
+}
+
+/// Something that can be seen as a mutable array of `N` elements
+pub trait AsMutArray<const N: usize>: AsArray<N> + AsMutSlice {
+    /// Returns the mutable array view of `Self`
+    fn as_mut_array(&mut self) -> &mut [Self::Element; N];
+}
+
+/// Fallible array views of `AsSlice` types
+///
+/// This trait is implemented for all the `AsSlice` types
+pub trait TryAsArray: AsSlice {
+    /// Returns the immutable array view of `Self`, or `None` if its length is not `N`
+    fn try_as_array<const N: usize>(&self) -> Option<&[Self::Element; N]>;
+}
+
+/// Fallible array views of `AsMutSlice` types
+///
+/// This trait is implemented for all the `AsMutSlice` types
+pub trait TryAsMutArray: TryAsArray + AsMutSlice {
+    /// Returns the mutable array view of `Self`, or `None` if its length is not `N`
+    fn try_as_mut_array<const N: usize>(&mut self) -> Option<&mut [Self::Element; N]>;
+}
+
+impl<'a, S, const N: usize> AsArray<N> for &'a S
+where
+    S: ?Sized + AsArray<N>,
+{
+    fn as_array(&self) -> &[S::Element; N] {
+        (**self).as_array()
+    }
+}
+
+impl<'a, S, const N: usize> AsArray<N> for &'a mut S
+where
+    S: ?Sized + AsArray<N>,
+{
+    fn as_array(&self) -> &[S::Element; N] {
+        (**self).as_array()
+    }
+}
+
+impl<'a, S, const N: usize> AsMutArray<N> for &'a mut S
+where
+    S: ?Sized + AsMutArray<N>,
+{
+    fn as_mut_array(&mut self) -> &mut [S::Element; N] {
+        (**self).as_mut_array()
+    }
+}
+
+impl<T, const N: usize> AsArray<N> for [T; N] {
+    fn as_array(&self) -> &[T; N] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMutArray<N> for [T; N] {
+    fn as_mut_array(&mut self) -> &mut [T; N] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> AsArray<N> for Box<[T; N]> {
+    fn as_array(&self) -> &[T; N] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> AsMutArray<N> for Box<[T; N]> {
+    fn as_mut_array(&mut self) -> &mut [T; N] {
+        self
+    }
+}
+
+// NOTE `N` can't be inferred from the `typenum` length so callers have to name it, e.g. through an
+// `AsArray<16>` bound
+#[cfg(feature = "generic-array-1")]
+impl<T, const N: usize> AsArray<N>
+    for generic_array_1::GenericArray<T, generic_array_1::ConstArrayLength<N>>
+where
+    generic_array_1::typenum::Const<N>: generic_array_1::IntoArrayLength,
+{
+    fn as_array(&self) -> &[T; N] {
+        self.as_ref()
+    }
+}
+
+#[cfg(feature = "generic-array-1")]
+impl<T, const N: usize> AsMutArray<N>
+    for generic_array_1::GenericArray<T, generic_array_1::ConstArrayLength<N>>
+where
+    generic_array_1::typenum::Const<N>: generic_array_1::IntoArrayLength,
+{
+    fn as_mut_array(&mut self) -> &mut [T; N] {
+        self.as_mut()
+    }
+}
+
+impl<S> TryAsArray for S
+where
+    S: ?Sized + AsSlice,
+{
+    fn try_as_array<const N: usize>(&self) -> Option<&[S::Element; N]> {
+        <&[S::Element; N]>::try_from(self.as_slice()).ok()
+    }
+}
+
+impl<S> TryAsMutArray for S
+where
+    S: ?Sized + AsMutSlice,
+{
+    fn try_as_mut_array<const N: usize>(&mut self) -> Option<&mut [S::Element; N]> {
+        <&mut [S::Element; N]>::try_from(self.as_mut_slice()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "generic-array-1")]
+    #[test]
+    fn generic_array_1() {
+        use generic_array_1::{typenum::U16, GenericArray};
+
+        use super::{AsArray, AsMutArray};
+
+        fn key<K>(key: &K) -> [u8; 16]
+        where
+            K: AsArray<16, Element = u8>,
+        {
+            *key.as_array()
+        }
+
+        let mut array = GenericArray::<u8, U16>::default();
+        AsMutArray::<16>::as_mut_array(&mut array)[15] = 1;
+
+        let mut expected = [0; 16];
+        expected[15] = 1;
+        assert_eq!(key(&array), expected);
+        assert_eq!(key(&&mut array), expected);
+    }
+}
@@ -0,0 +1,127 @@
+//! Byte views of plain-old-data slices
+
+use core::{mem, slice};
+
+use {AsMutSlice, AsSlice};
+
+/// Plain-old-data types: types for which every bit pattern is a valid value and that contain no
+/// padding bytes
+///
+/// This trait is sealed and can't be implemented outside this crate.
+pub trait Pod: Copy + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! pod {
+    ($($ty:ty),+) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl Pod for $ty {}
+        )+
+    }
+}
+
+pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<T, const N: usize> sealed::Sealed for [T; N] where T: Pod {}
+
+impl<T, const N: usize> Pod for [T; N] where T: Pod {}
+
+/// Something that can be seen as an immutable byte slice
+///
+/// This trait is implemented for all the `AsSlice` types whose `Element` is `Pod`
+pub trait AsByteSlice {
+    /// Returns the immutable byte slice view of `Self`
+    fn as_byte_slice(&self) -> &[u8];
+}
+
+/// Something that can be seen as a mutable byte slice
+///
+/// This trait is implemented for all the `AsMutSlice` types whose `Element` is `Pod`
+pub trait AsMutByteSlice: AsByteSlice {
+    /// Returns the mutable byte slice view of `Self`
+    fn as_mut_byte_slice(&mut self) -> &mut [u8];
+}
+
+impl<S> AsByteSlice for S
+where
+    S: ?Sized + AsSlice,
+    S::Element: Pod,
+{
+    fn as_byte_slice(&self) -> &[u8] {
+        let slice = self.as_slice();
+
+        // NOTE(unsafe) `Pod` types have no padding and `u8` has an alignment of 1
+        unsafe { slice::from_raw_parts(slice.as_ptr() as *const u8, mem::size_of_val(slice)) }
+    }
+}
+
+impl<S> AsMutByteSlice for S
+where
+    S: ?Sized + AsMutSlice,
+    S::Element: Pod,
+{
+    fn as_mut_byte_slice(&mut self) -> &mut [u8] {
+        let slice = self.as_mut_slice();
+        let len = mem::size_of_val(slice);
+
+        // NOTE(unsafe) `Pod` types have no padding, any bit pattern is a valid `Pod` value and `u8`
+        // has an alignment of 1
+        unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut u8, len) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsByteSlice, AsMutByteSlice};
+
+    #[test]
+    fn u16_array() {
+        let mut words = [0x0102u16, 0x0304, 0x0506];
+
+        let bytes = words.as_byte_slice();
+        assert_eq!(bytes.len(), 6);
+        assert_eq!(bytes.as_ptr(), words.as_ptr() as *const u8);
+        for (chunk, word) in bytes.chunks(2).zip(words.iter()) {
+            assert_eq!(chunk, word.to_ne_bytes());
+        }
+
+        words.as_mut_byte_slice()[2..4].copy_from_slice(&0xaabbu16.to_ne_bytes());
+        assert_eq!(words, [0x0102, 0xaabb, 0x0506]);
+    }
+
+    #[test]
+    fn nested_u32_array() {
+        let mut pairs = [[1u32, 2], [3, 4], [5, 6]];
+
+        let bytes = pairs.as_byte_slice();
+        assert_eq!(bytes.len(), 24);
+        for (chunk, word) in bytes.chunks(4).zip(1u32..) {
+            assert_eq!(chunk, word.to_ne_bytes());
+        }
+
+        pairs.as_mut_byte_slice()[20..].copy_from_slice(&7u32.to_ne_bytes());
+        assert_eq!(pairs, [[1, 2], [3, 4], [5, 7]]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut words: [u32; 0] = [];
+        assert!(words.as_byte_slice().is_empty());
+        assert!(words.as_mut_byte_slice().is_empty());
+
+        let words = [1u64, 2];
+        assert!(words[1..1].as_byte_slice().is_empty());
+    }
+
+    #[test]
+    fn zero_sized_elements() {
+        let mut empties = [[0u8; 0]; 4];
+
+        assert!(empties.as_byte_slice().is_empty());
+        assert!(empties.as_mut_byte_slice().is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+//! Checked casts between slices of plain-old-data types
+//!
+//! # Example
+//!
+//! ```
+//! use as_slice::cast::{AsSliceOf, Error};
+//!
+//! #[repr(align(4))]
+//! struct Aligned([u8; 8]);
+//!
+//! let buffer = Aligned([1, 0, 0, 0, 2, 0, 0, 0]);
+//!
+//! let words: &[u32] = buffer.0.try_as_slice_of().unwrap();
+//! assert_eq!(words.len(), 2);
+//!
+//! assert_eq!(buffer.0[..7].try_as_slice_of::<u32>(), Err(Error::Length));
+//! assert_eq!(buffer.0[1..5].try_as_slice_of::<u32>(), Err(Error::Misaligned));
+//! ```
+
+use core::{fmt, mem, slice};
+
+use {AsByteSlice, AsMutByteSlice, AsMutSlice, AsSlice, Pod};
+
+/// Reasons why a cast can fail
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The start of the slice is not suitably aligned for the target type
+    Misaligned,
+    /// The size of the slice, in bytes, is not a multiple of the size of the target type
+    Length,
+    /// The target type is zero sized so the length of the resulting slice is undefined
+    ZeroSized,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Error::Misaligned => "slice is not aligned to the target type",
+            Error::Length => "slice size is not a multiple of the target type size",
+            Error::ZeroSized => "target type is zero sized",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Something that can be seen as an immutable slice of any `Pod` type
+///
+/// This trait is implemented for all the `AsSlice` types whose `Element` is `Pod`
+pub trait AsSliceOf: AsByteSlice {
+    /// Returns the immutable view of `Self` as a slice of `U`s
+    ///
+    /// Returns an error if `Self` is not aligned to `U` or if its size is not a multiple of the
+    /// size of `U`. An empty `Self` can always be cast to a (non zero sized) `U`
+    fn try_as_slice_of<U>(&self) -> Result<&[U], Error>
+    where
+        U: Pod;
+}
+
+/// Something that can be seen as a mutable slice of any `Pod` type
+///
+/// This trait is implemented for all the `AsMutSlice` types whose `Element` is `Pod`
+pub trait AsMutSliceOf: AsMutByteSlice {
+    /// Returns the mutable view of `Self` as a slice of `U`s
+    ///
+    /// Returns an error if `Self` is not aligned to `U` or if its size is not a multiple of the
+    /// size of `U`. An empty `Self` can always be cast to a (non zero sized) `U`
+    fn try_as_mut_slice_of<U>(&mut self) -> Result<&mut [U], Error>
+    where
+        U: Pod;
+}
+
+impl<S> AsSliceOf for S
+where
+    S: ?Sized + AsSlice,
+    S::Element: Pod,
+{
+    fn try_as_slice_of<U>(&self) -> Result<&[U], Error>
+    where
+        U: Pod,
+    {
+        let bytes = self.as_byte_slice();
+        let len = check::<U>(bytes.as_ptr() as usize, bytes.len())?;
+
+        if len == 0 {
+            return Ok(&[]);
+        }
+
+        // NOTE(unsafe) alignment and size have been checked; any bit pattern is a valid `U`
+        unsafe { Ok(slice::from_raw_parts(bytes.as_ptr() as *const U, len)) }
+    }
+}
+
+impl<S> AsMutSliceOf for S
+where
+    S: ?Sized + AsMutSlice,
+    S::Element: Pod,
+{
+    fn try_as_mut_slice_of<U>(&mut self) -> Result<&mut [U], Error>
+    where
+        U: Pod,
+    {
+        let bytes = self.as_mut_byte_slice();
+        let len = check::<U>(bytes.as_ptr() as usize, bytes.len())?;
+
+        if len == 0 {
+            return Ok(&mut []);
+        }
+
+        // NOTE(unsafe) alignment and size have been checked; any bit pattern is a valid `U`
+        unsafe { Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut U, len)) }
+    }
+}
+
+/// Returns the number of `U`s that fit in `size` bytes starting at address `addr`
+///
+/// An empty slice can be cast to any sized `U`, regardless of the alignment of `addr`; the caller
+/// must then not use `addr` to build the resulting slice
+fn check<U>(addr: usize, size: usize) -> Result<usize, Error> {
+    let elem_size = mem::size_of::<U>();
+
+    if elem_size == 0 {
+        Err(Error::ZeroSized)
+    } else if size == 0 {
+        Ok(0)
+    } else if addr % mem::align_of::<U>() != 0 {
+        Err(Error::Misaligned)
+    } else if size % elem_size != 0 {
+        Err(Error::Length)
+    } else {
+        Ok(size / elem_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsMutSliceOf, AsSliceOf, Error};
+    use AsByteSlice;
+
+    #[test]
+    fn ok() {
+        let words = [1u32, 2];
+        let bytes = words.as_byte_slice();
+
+        assert_eq!(bytes.try_as_slice_of::<u32>(), Ok(&[1, 2][..]));
+        assert_eq!(bytes.try_as_slice_of::<u16>().map(|s| s.len()), Ok(4));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!([0u8; 0].try_as_slice_of::<u32>(), Ok(&[][..]));
+        assert_eq!([0u8; 0].try_as_mut_slice_of::<u64>(), Ok(&mut [][..]));
+
+        // the empty tail of a slice is not aligned to `u32`
+        let words = [0u32; 2];
+        assert_eq!(
+            words.as_byte_slice()[1..1].try_as_slice_of::<u32>(),
+            Ok(&[][..])
+        );
+    }
+
+    #[test]
+    fn length() {
+        let words = [0u32; 2];
+        let bytes = words.as_byte_slice();
+
+        assert_eq!(bytes[..7].try_as_slice_of::<u32>(), Err(Error::Length));
+        assert_eq!(bytes[..3].try_as_slice_of::<u16>(), Err(Error::Length));
+    }
+
+    #[test]
+    fn misaligned() {
+        let words = [0u32; 2];
+        let bytes = words.as_byte_slice();
+
+        assert_eq!(bytes[1..5].try_as_slice_of::<u32>(), Err(Error::Misaligned));
+        assert_eq!(bytes[1..3].try_as_slice_of::<u16>(), Err(Error::Misaligned));
+        assert_eq!(bytes[2..6].try_as_slice_of::<u16>().map(|s| s.len()), Ok(2));
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut bytes = [0u8; 4];
+
+        assert_eq!(bytes.try_as_slice_of::<[u8; 0]>(), Err(Error::ZeroSized));
+        assert_eq!(
+            bytes.try_as_mut_slice_of::<[u32; 0]>(),
+            Err(Error::ZeroSized)
+        );
+        assert_eq!([0u8; 0].try_as_slice_of::<[u8; 0]>(), Err(Error::ZeroSized));
+    }
+}
@@ -0,0 +1,316 @@
+//! Two buffers presented as a single logical sequence
+
+use core::cmp;
+use core::iter::Chain;
+use core::ops::Index;
+use core::slice::Iter;
+
+use {AsMutSlice, AsMutSliceList, AsSlice, AsSliceList};
+
+/// Two `AsSlice` buffers presented as a single logical sequence, without copying
+///
+/// # Example
+///
+/// ```
+/// use as_slice::Concat;
+///
+/// let frame = Concat::new([0xaa, 0x55], [1, 2, 3]);
+///
+/// assert_eq!(frame.len(), 5);
+/// assert_eq!(frame[2], 1);
+/// assert!(frame.iter().eq([0xaa, 0x55, 1, 2, 3].iter()));
+///
+/// // chunks can straddle the boundary between the two buffers
+/// let mut chunks = frame.chunks(3);
+/// assert_eq!(chunks.next().map(|c| c.into_inner()), Some((&[0xaa, 0x55][..], &[1][..])));
+/// assert_eq!(chunks.next().map(|c| c.into_inner()), Some((&[][..], &[2, 3][..])));
+/// assert!(chunks.next().is_none());
+///
+/// let mut packet = [0; 5];
+/// assert_eq!(frame.gather_into(&mut packet), 5);
+/// assert_eq!(packet, [0xaa, 0x55, 1, 2, 3]);
+/// ```
+pub struct Concat<A, B>
+where
+    A: AsSlice,
+    B: AsSlice<Element = A::Element>,
+{
+    first: A,
+    second: B,
+}
+
+impl<A, B> Concat<A, B>
+where
+    A: AsSlice,
+    B: AsSlice<Element = A::Element>,
+{
+    /// Presents `first` followed by `second` as a single sequence
+    pub fn new(first: A, second: B) -> Self {
+        Concat { first, second }
+    }
+
+    /// Releases the two buffers
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    /// Returns the total number of elements
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total overflows `usize`, which can only happen when the elements are zero
+    /// sized as the buffers are otherwise already in memory
+    pub fn len(&self) -> usize {
+        self.first
+            .as_slice()
+            .len()
+            .checked_add(self.second.as_slice().len())
+            .expect("concatenated length overflows `usize`")
+    }
+
+    /// Returns `true` if both buffers are empty
+    pub fn is_empty(&self) -> bool {
+        self.first.as_slice().is_empty() && self.second.as_slice().is_empty()
+    }
+
+    /// Returns the element at index `i`, or `None` if out of bounds
+    pub fn get(&self, i: usize) -> Option<&A::Element> {
+        let first = self.first.as_slice();
+
+        if i < first.len() {
+            Some(&first[i])
+        } else {
+            self.second.as_slice().get(i - first.len())
+        }
+    }
+
+    /// Returns an iterator over all the elements
+    pub fn iter(&self) -> Chain<Iter<'_, A::Element>, Iter<'_, A::Element>> {
+        self.first.as_slice().iter().chain(self.second.as_slice())
+    }
+
+    /// Returns an iterator over `chunk_size` elements at a time
+    ///
+    /// Each chunk is itself a `Concat` as it may straddle the boundary between the two buffers.
+    /// The last chunk may be shorter than `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0
+    pub fn chunks(&self, chunk_size: usize) -> ConcatChunks<'_, A::Element> {
+        assert!(chunk_size != 0);
+
+        ConcatChunks {
+            first: self.first.as_slice(),
+            second: self.second.as_slice(),
+            chunk_size,
+        }
+    }
+
+    /// Copies the elements into `dst` until either runs out and returns the number of elements
+    /// copied
+    pub fn gather_into<S>(&self, dst: &mut S) -> usize
+    where
+        S: ?Sized + AsMutSlice<Element = A::Element>,
+        A::Element: Copy,
+    {
+        AsSliceList::gather_into(self, dst)
+    }
+
+    /// Copies the elements into `dst` until either runs out and returns the number of elements
+    /// copied
+    ///
+    /// This is the same as `gather_into`
+    pub fn copy_to<S>(&self, dst: &mut S) -> usize
+    where
+        S: ?Sized + AsMutSlice<Element = A::Element>,
+        A::Element: Copy,
+    {
+        self.gather_into(dst)
+    }
+}
+
+impl<A, B> Concat<A, B>
+where
+    A: AsMutSlice,
+    B: AsMutSlice<Element = A::Element>,
+{
+    /// Returns a mutable reference to the element at index `i`, or `None` if out of bounds
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut A::Element> {
+        let first = self.first.as_mut_slice();
+
+        if i < first.len() {
+            Some(&mut first[i])
+        } else {
+            let i = i - first.len();
+            self.second.as_mut_slice().get_mut(i)
+        }
+    }
+
+    /// Copies the elements of `src` into the two buffers until either runs out and returns the
+    /// number of elements copied
+    pub fn scatter_from<S>(&mut self, src: &S) -> usize
+    where
+        S: ?Sized + AsSlice<Element = A::Element>,
+        A::Element: Copy,
+    {
+        AsMutSliceList::scatter_from(self, src)
+    }
+}
+
+impl<A, B> Index<usize> for Concat<A, B>
+where
+    A: AsSlice,
+    B: AsSlice<Element = A::Element>,
+{
+    type Output = A::Element;
+
+    fn index(&self, i: usize) -> &A::Element {
+        self.get(i).expect("index out of bounds")
+    }
+}
+
+impl<A, B> AsSliceList for Concat<A, B>
+where
+    A: AsSlice,
+    B: AsSlice<Element = A::Element>,
+{
+    type Element = A::Element;
+
+    fn segment_count(&self) -> usize {
+        2
+    }
+
+    fn segment(&self, i: usize) -> Option<&[A::Element]> {
+        match i {
+            0 => Some(self.first.as_slice()),
+            1 => Some(self.second.as_slice()),
+            _ => None,
+        }
+    }
+}
+
+impl<A, B> AsMutSliceList for Concat<A, B>
+where
+    A: AsMutSlice,
+    B: AsMutSlice<Element = A::Element>,
+{
+    fn segment_mut(&mut self, i: usize) -> Option<&mut [A::Element]> {
+        match i {
+            0 => Some(self.first.as_mut_slice()),
+            1 => Some(self.second.as_mut_slice()),
+            _ => None,
+        }
+    }
+}
+
+/// Iterator over the chunks of a `Concat`
+pub struct ConcatChunks<'a, T> {
+    first: &'a [T],
+    second: &'a [T],
+    chunk_size: usize,
+}
+
+impl<'a, T> Iterator for ConcatChunks<'a, T> {
+    type Item = Concat<&'a [T], &'a [T]>;
+
+    fn next(&mut self) -> Option<Concat<&'a [T], &'a [T]>> {
+        if self.first.is_empty() && self.second.is_empty() {
+            return None;
+        }
+
+        let n = cmp::min(self.chunk_size, self.first.len());
+        let (first, rest) = self.first.split_at(n);
+        self.first = rest;
+
+        let n = cmp::min(self.chunk_size - n, self.second.len());
+        let (second, rest) = self.second.split_at(n);
+        self.second = rest;
+
+        Some(Concat::new(first, second))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // NOTE the total length overflows `usize` if `T` is zero sized so the full chunks of each
+        // buffer are counted separately, plus the one or two chunks their remainders make up
+        let size = self.chunk_size;
+        let (first, second) = (self.first.len() % size, self.second.len() % size);
+        let partial = if first == 0 && second == 0 {
+            0
+        } else if first > size - second {
+            2
+        } else {
+            1
+        };
+
+        match (self.first.len() / size)
+            .checked_add(self.second.len() / size)
+            .and_then(|n| n.checked_add(partial))
+        {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Concat;
+
+    #[test]
+    fn chunks_size_hint() {
+        let concat = Concat::new([1u8, 2, 3, 4, 5], [6u8, 7]);
+
+        for chunk_size in 1..10 {
+            let chunks = concat.chunks(chunk_size);
+            let (lower, upper) = chunks.size_hint();
+
+            assert_eq!(Some(lower), upper);
+            assert_eq!(lower, chunks.count());
+        }
+    }
+
+    #[test]
+    fn chunks_size_hint_max_chunk_size() {
+        let concat = Concat::new([1u8, 2], [3u8]);
+
+        assert_eq!(concat.chunks(usize::MAX).size_hint(), (1, Some(1)));
+        assert_eq!(concat.chunks(usize::MAX).count(), 1);
+    }
+
+    #[test]
+    fn chunks_size_hint_empty() {
+        let concat = Concat::new([0u8; 0], [0u8; 0]);
+
+        assert_eq!(concat.chunks(3).size_hint(), (0, Some(0)));
+        assert_eq!(concat.chunks(3).count(), 0);
+    }
+
+    #[test]
+    fn chunks_size_hint_zero_sized() {
+        let concat = Concat::new([(); usize::MAX], [(); 2]);
+
+        assert_eq!(
+            concat.chunks(2).size_hint(),
+            (usize::MAX / 2 + 2, Some(usize::MAX / 2 + 2))
+        );
+        assert_eq!(concat.chunks(1).size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "concatenated length overflows `usize`")]
+    fn len_overflow() {
+        let concat = Concat::new([(); usize::MAX], [(); 1]);
+
+        concat.len();
+    }
+
+    #[test]
+    fn copy_to() {
+        let concat = Concat::new([1u8, 2], [3u8]);
+        let mut dst = [0; 4];
+
+        assert_eq!(concat.copy_to(&mut dst), 3);
+        assert_eq!(dst, [1, 2, 3, 0]);
+    }
+}
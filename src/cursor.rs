@@ -0,0 +1,522 @@
+//! Byte cursor for serializing into and deserializing from `As{,Mut}Slice` buffers
+//!
+//! # Example
+//!
+//! ```
+//! use as_slice::cursor::{Cursor, Error};
+//!
+//! let mut tx = Cursor::new([0u8; 6]);
+//! tx.put_u16_le(0x0201).unwrap();
+//! tx.put_u32_be(0x03040506).unwrap();
+//! assert_eq!(tx.put_u8(7), Err(Error));
+//!
+//! let mut rx = Cursor::new(tx.into_inner());
+//! assert_eq!(rx.get_u8(), Ok(1));
+//! assert_eq!(rx.get_u8(), Ok(2));
+//! assert_eq!(rx.get_u32_le(), Ok(0x06050403));
+//! assert_eq!(rx.remaining(), 0);
+//! ```
+//!
+//! # `embedded-io`
+//!
+//! With the `embedded-io` feature enabled `Cursor` implements the `embedded_io` `Read`, `BufRead`
+//! and `Write` traits, and with the `embedded-io-async` feature it also implements their
+//! `embedded_io_async` counterparts. Reads return `Ok(0)` once the end of the buffer is reached;
+//! writes are short if the buffer has less space than requested and fail with `Error` (error kind
+//! `WriteZero`) if the buffer is full.
+//!
+//! # `std`
+//!
+//! With the `std` feature enabled `Cursor` implements the `std::io` `Read`, `BufRead`, `Write`
+//! and `Seek` traits. These follow the conventions of `std::io::Cursor`: reads return `Ok(0)` at
+//! the end of the buffer, writes return `Ok(0)` once the buffer is full and seeking past the end
+//! of the buffer is allowed.
+
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+use core::cmp;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+use core::{fmt, mem};
+
+#[cfg(feature = "embedded-io-async")]
+use core::future::{self, Future};
+#[cfg(feature = "embedded-io-async")]
+use core::task::Poll;
+
+#[cfg(feature = "embedded-io")]
+use embedded_io_0_6 as embedded_io;
+#[cfg(feature = "embedded-io-async")]
+use embedded_io_async_0_6 as embedded_io_async;
+#[cfg(feature = "std")]
+use std::io;
+
+use {AsMutSlice, AsSlice};
+
+/// Error returned when there are not enough bytes remaining in the buffer
+///
+/// The cursor position is left unchanged when an operation fails
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("not enough bytes remaining in the buffer")
+    }
+}
+
+/// A cursor over a byte buffer
+///
+/// Reading (`get_*`) methods are available on any `AsSlice<Element = u8>` buffer and writing
+/// (`put_*`) methods on any `AsMutSlice<Element = u8>` buffer. Both advance the cursor position.
+pub struct Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    buffer: B,
+    pos: usize,
+}
+
+macro_rules! get {
+    ($($name:ident: $ty:ty = $from:ident, $doc:expr;)+) => {
+        $(
+            #[doc = $doc]
+            pub fn $name(&mut self) -> Result<$ty, Error> {
+                let mut bytes = [0; mem::size_of::<$ty>()];
+                self.get_slice(&mut bytes)?;
+                Ok(<$ty>::$from(bytes))
+            }
+        )+
+    }
+}
+
+macro_rules! put {
+    ($($name:ident: $ty:ty = $to:ident, $doc:expr;)+) => {
+        $(
+            #[doc = $doc]
+            pub fn $name(&mut self, value: $ty) -> Result<(), Error> {
+                self.put_slice(&value.$to())
+            }
+        )+
+    }
+}
+
+impl<B> Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    /// Creates a cursor positioned at the start of `buffer`
+    pub fn new(buffer: B) -> Self {
+        Cursor { buffer, pos: 0 }
+    }
+
+    /// Returns the current position of the cursor
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Moves the cursor to `pos`
+    ///
+    /// `pos` may be past the end of the buffer, in which case all the subsequent non-empty
+    /// operations fail
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Returns the number of bytes between the cursor position and the end of the buffer
+    pub fn remaining(&self) -> usize {
+        self.buffer.as_slice().len().saturating_sub(self.pos)
+    }
+
+    /// Returns a reference to the underlying buffer
+    pub fn get_ref(&self) -> &B {
+        &self.buffer
+    }
+
+    /// Returns a mutable reference to the underlying buffer
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buffer
+    }
+
+    /// Releases the underlying buffer
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+
+    /// Returns the next `n` bytes and advances the cursor past them
+    pub fn get_bytes(&mut self, n: usize) -> Result<&[u8], Error> {
+        if n == 0 {
+            return Ok(&[]);
+        } else if n > self.remaining() {
+            return Err(Error);
+        }
+
+        let start = self.pos;
+        self.pos += n;
+
+        Ok(&self.buffer.as_slice()[start..self.pos])
+    }
+
+    /// Fills `out` with the next bytes and advances the cursor past them
+    pub fn get_slice<S>(&mut self, out: &mut S) -> Result<(), Error>
+    where
+        S: ?Sized + AsMutSlice<Element = u8>,
+    {
+        let out = out.as_mut_slice();
+        out.copy_from_slice(self.get_bytes(out.len())?);
+
+        Ok(())
+    }
+
+    get! {
+        get_u8: u8 = from_le_bytes, "Reads a `u8`";
+        get_i8: i8 = from_le_bytes, "Reads an `i8`";
+        get_u16_le: u16 = from_le_bytes, "Reads a little endian `u16`";
+        get_u16_be: u16 = from_be_bytes, "Reads a big endian `u16`";
+        get_i16_le: i16 = from_le_bytes, "Reads a little endian `i16`";
+        get_i16_be: i16 = from_be_bytes, "Reads a big endian `i16`";
+        get_u32_le: u32 = from_le_bytes, "Reads a little endian `u32`";
+        get_u32_be: u32 = from_be_bytes, "Reads a big endian `u32`";
+        get_i32_le: i32 = from_le_bytes, "Reads a little endian `i32`";
+        get_i32_be: i32 = from_be_bytes, "Reads a big endian `i32`";
+        get_u64_le: u64 = from_le_bytes, "Reads a little endian `u64`";
+        get_u64_be: u64 = from_be_bytes, "Reads a big endian `u64`";
+        get_i64_le: i64 = from_le_bytes, "Reads a little endian `i64`";
+        get_i64_be: i64 = from_be_bytes, "Reads a big endian `i64`";
+        get_f32_le: f32 = from_le_bytes, "Reads a little endian `f32`";
+        get_f32_be: f32 = from_be_bytes, "Reads a big endian `f32`";
+        get_f64_le: f64 = from_le_bytes, "Reads a little endian `f64`";
+        get_f64_be: f64 = from_be_bytes, "Reads a big endian `f64`";
+    }
+}
+
+impl<B> Cursor<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    /// Writes `data` and advances the cursor past it
+    pub fn put_slice<S>(&mut self, data: &S) -> Result<(), Error>
+    where
+        S: ?Sized + AsSlice<Element = u8>,
+    {
+        let data = data.as_slice();
+
+        if data.is_empty() {
+            return Ok(());
+        } else if data.len() > self.remaining() {
+            return Err(Error);
+        }
+
+        let start = self.pos;
+        self.pos += data.len();
+        self.buffer.as_mut_slice()[start..self.pos].copy_from_slice(data);
+
+        Ok(())
+    }
+
+    put! {
+        put_u8: u8 = to_le_bytes, "Writes a `u8`";
+        put_i8: i8 = to_le_bytes, "Writes an `i8`";
+        put_u16_le: u16 = to_le_bytes, "Writes a little endian `u16`";
+        put_u16_be: u16 = to_be_bytes, "Writes a big endian `u16`";
+        put_i16_le: i16 = to_le_bytes, "Writes a little endian `i16`";
+        put_i16_be: i16 = to_be_bytes, "Writes a big endian `i16`";
+        put_u32_le: u32 = to_le_bytes, "Writes a little endian `u32`";
+        put_u32_be: u32 = to_be_bytes, "Writes a big endian `u32`";
+        put_i32_le: i32 = to_le_bytes, "Writes a little endian `i32`";
+        put_i32_be: i32 = to_be_bytes, "Writes a big endian `i32`";
+        put_u64_le: u64 = to_le_bytes, "Writes a little endian `u64`";
+        put_u64_be: u64 = to_be_bytes, "Writes a big endian `u64`";
+        put_i64_le: i64 = to_le_bytes, "Writes a little endian `i64`";
+        put_i64_be: i64 = to_be_bytes, "Writes a big endian `i64`";
+        put_f32_le: f32 = to_le_bytes, "Writes a little endian `f32`";
+        put_f32_be: f32 = to_be_bytes, "Writes a big endian `f32`";
+        put_f64_le: f64 = to_le_bytes, "Writes a little endian `f64`";
+        put_f64_be: f64 = to_be_bytes, "Writes a big endian `f64`";
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Error for Error {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        embedded_io::ErrorKind::WriteZero
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<B> embedded_io::ErrorType for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    type Error = Error;
+}
+
+#[cfg(feature = "embedded-io")]
+impl<B> embedded_io::Read for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let n = cmp::min(buf.len(), self.remaining());
+        buf[..n].copy_from_slice(self.get_bytes(n)?);
+
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<B> embedded_io::BufRead for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn fill_buf(&mut self) -> Result<&[u8], Error> {
+        let n = self.remaining();
+        let bytes = self.buffer.as_slice();
+
+        Ok(&bytes[bytes.len() - n..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_add(amt);
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl<B> embedded_io::Write for Cursor<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        let n = cmp::min(buf.len(), self.remaining());
+
+        if n == 0 && !buf.is_empty() {
+            return Err(Error);
+        }
+
+        self.put_slice(&buf[..n])?;
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+// NOTE these operations never block so the futures complete on their first poll. `embedded-io-async`
+// itself requires Rust 1.75
+#[cfg(feature = "embedded-io-async")]
+#[clippy::msrv = "1.75"]
+impl<B> embedded_io_async::Read for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> impl Future<Output = Result<usize, Error>> {
+        future::poll_fn(move |_| Poll::Ready(embedded_io::Read::read(self, buf)))
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+#[clippy::msrv = "1.75"]
+impl<B> embedded_io_async::BufRead for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn fill_buf(&mut self) -> impl Future<Output = Result<&[u8], Error>> {
+        let mut this = Some(self);
+        future::poll_fn(move |_| {
+            Poll::Ready(embedded_io::BufRead::fill_buf(
+                this.take().expect("polled after completion"),
+            ))
+        })
+    }
+
+    fn consume(&mut self, amt: usize) {
+        embedded_io::BufRead::consume(self, amt)
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+#[clippy::msrv = "1.75"]
+impl<B> embedded_io_async::Write for Cursor<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    fn write(&mut self, buf: &[u8]) -> impl Future<Output = Result<usize, Error>> {
+        future::poll_fn(move |_| Poll::Ready(embedded_io::Write::write(self, buf)))
+    }
+
+    fn flush(&mut self) -> impl Future<Output = Result<(), Error>> {
+        future::ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl<B> io::Read for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = cmp::min(buf.len(), self.remaining());
+        buf[..n].copy_from_slice(self.get_bytes(n).expect("unreachable"));
+
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B> io::BufRead for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let n = self.remaining();
+        let bytes = self.buffer.as_slice();
+
+        Ok(&bytes[bytes.len() - n..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_add(amt);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B> io::Write for Cursor<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = cmp::min(buf.len(), self.remaining());
+        self.put_slice(&buf[..n]).expect("unreachable");
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B> io::Seek for Cursor<B>
+where
+    B: AsSlice<Element = u8>,
+{
+    fn seek(&mut self, style: io::SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            io::SeekFrom::Start(n) => (0, n as i128),
+            io::SeekFrom::End(n) => (self.buffer.as_slice().len(), n as i128),
+            io::SeekFrom::Current(n) => (self.pos, n as i128),
+        };
+
+        match usize::try_from(base as i128 + offset) {
+            Ok(pos) => {
+                self.pos = pos;
+                Ok(pos as u64)
+            }
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cursor, Error};
+
+    #[test]
+    fn zero_length_past_the_end() {
+        let mut cursor = Cursor::new([0u8; 4]);
+        cursor.set_position(6);
+
+        assert_eq!(cursor.get_bytes(0), Ok(&[][..]));
+        assert_eq!(cursor.put_slice(&[]), Ok(()));
+        assert_eq!(cursor.get_bytes(1), Err(Error));
+        assert_eq!(cursor.put_u8(0), Err(Error));
+        assert_eq!(cursor.position(), 6);
+    }
+
+    #[cfg(feature = "embedded-io")]
+    #[test]
+    fn embedded_io_read_eof() {
+        use embedded_io_0_6::Read;
+
+        let mut cursor = Cursor::new([1u8, 2, 3]);
+        let mut buf = [0; 2];
+
+        assert_eq!(cursor.read(&mut buf), Ok(2));
+        assert_eq!(buf, [1, 2]);
+        assert_eq!(cursor.read(&mut buf), Ok(1));
+        assert_eq!(buf[0], 3);
+        assert_eq!(cursor.read(&mut buf), Ok(0));
+        assert_eq!(cursor.read(&mut buf), Ok(0));
+
+        cursor.set_position(10);
+        assert_eq!(cursor.read(&mut buf), Ok(0));
+    }
+
+    #[cfg(feature = "embedded-io")]
+    #[test]
+    fn embedded_io_short_write() {
+        use embedded_io_0_6::Write;
+
+        let mut cursor = Cursor::new([0u8; 3]);
+
+        assert_eq!(cursor.write(&[1, 2]), Ok(2));
+        assert_eq!(cursor.write(&[3, 4, 5]), Ok(1));
+        assert_eq!(cursor.into_inner(), [1, 2, 3]);
+    }
+
+    #[cfg(feature = "embedded-io")]
+    #[test]
+    fn embedded_io_write_full() {
+        use embedded_io_0_6::{Error as _, ErrorKind, Write};
+
+        let mut cursor = Cursor::new([0u8; 2]);
+
+        assert_eq!(cursor.write(&[1, 2]), Ok(2));
+        assert_eq!(cursor.write(&[]), Ok(0));
+
+        let e = cursor.write(&[3]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::WriteZero);
+        assert_eq!(
+            cursor.write_all(&[3]).map_err(|e| e.kind()),
+            Err(ErrorKind::WriteZero)
+        );
+    }
+
+    #[cfg(feature = "embedded-io-async")]
+    #[test]
+    fn embedded_io_async() {
+        use embedded_io_async_0_6::{Read, Write};
+
+        let mut cursor = Cursor::new([0u8; 3]);
+        assert_eq!(block_on(cursor.write(&[1, 2, 3, 4])), Ok(3));
+        assert_eq!(block_on(cursor.write(&[4])), Err(Error));
+
+        let mut cursor = Cursor::new(cursor.into_inner());
+        let mut buf = [0; 4];
+        assert_eq!(block_on(cursor.read(&mut buf)), Ok(3));
+        assert_eq!(block_on(cursor.read(&mut buf)), Ok(0));
+    }
+
+    // the `Cursor` futures are always ready so they only need to be polled once
+    #[cfg(feature = "embedded-io-async")]
+    fn block_on<F>(future: F) -> F::Output
+    where
+        F: ::core::future::Future,
+    {
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is not ready"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Buffer traits for DMA transfers
+//!
+//! These traits describe buffers whose memory can be handed to a DMA peripheral. HALs should bound
+//! their DMA APIs on `ReadBuffer` (memory to peripheral transfers) and `WriteBuffer` (peripheral
+//! to memory transfers) rather than on `StableDeref + As{,Mut}Slice` directly.
+//!
+//! # Soundness contract
+//!
+//! A DMA transfer keeps accessing the buffer memory after the API that started it has returned.
+//! For this to be sound:
+//!
+//! - the memory must not move while the transfer is in progress. This is why the blanket
+//!   implementations require `StableDeref`: moving the buffer handle (e.g. into a `Transfer`
+//!   struct) doesn't move the memory it points to.
+//!
+//! - the memory must remain valid until the transfer is over, even if the transfer handle is
+//!   `mem::forget`-ed. This is why the blanket implementations require `'static`.
+//!
+//! - the pointer / length pair must describe a single, contiguous and (for `WriteBuffer`)
+//!   writable region of memory of `Word`s.
+
+use core::ops::DerefMut;
+
+use stable_deref_trait::StableDeref;
+
+use {AsMutSlice, AsSlice};
+
+/// Word types DMA transfers can operate on: `u8`, `u16` and `u32`
+///
+/// This trait is sealed and can't be implemented outside this crate.
+pub trait Word: sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! word {
+    ($($ty:ty),+) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl Word for $ty {}
+        )+
+    }
+}
+
+word!(u8, u16, u32);
+
+/// A buffer that can be read from by a DMA transfer
+///
+/// # Safety
+///
+/// The implementer must uphold the soundness contract described in the [module level
+/// documentation](index.html): the memory returned by `read_buffer` must be valid for reads of
+/// `len` `Word`s, and must stay valid and in place until `self` is dropped, even if `self` is
+/// moved, and even if `self` is leaked.
+pub unsafe trait ReadBuffer {
+    /// The word type of the buffer
+    type Word: Word;
+
+    /// Returns a pointer to the start of the buffer and its length, in words
+    ///
+    /// # Safety
+    ///
+    /// Once this method has been called, the caller must not hand out a mutable reference to the
+    /// buffer memory while the pointer is in use (e.g. by calling `DerefMut` methods on `self`)
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize);
+}
+
+/// A buffer that can be written to by a DMA transfer
+///
+/// # Safety
+///
+/// The implementer must uphold the soundness contract described in the [module level
+/// documentation](index.html): the memory returned by `write_buffer` must be valid for writes of
+/// `len` `Word`s, and must stay valid and in place until `self` is dropped, even if `self` is
+/// moved, and even if `self` is leaked.
+pub unsafe trait WriteBuffer {
+    /// The word type of the buffer
+    type Word: Word;
+
+    /// Returns a pointer to the start of the buffer and its length, in words
+    ///
+    /// # Safety
+    ///
+    /// Once this method has been called, the caller must not hand out any reference to the buffer
+    /// memory while the pointer is in use (e.g. by calling `Deref` methods on `self`)
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize);
+}
+
+unsafe impl<B> ReadBuffer for B
+where
+    B: StableDeref + 'static,
+    B::Target: AsSlice,
+    <B::Target as AsSlice>::Element: Word,
+{
+    type Word = <B::Target as AsSlice>::Element;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        let slice = (**self).as_slice();
+
+        (slice.as_ptr(), slice.len())
+    }
+}
+
+unsafe impl<B> WriteBuffer for B
+where
+    B: StableDeref + DerefMut + 'static,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Word,
+{
+    type Word = <B::Target as AsSlice>::Element;
+
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        let slice = (**self).as_mut_slice();
+
+        (slice.as_mut_ptr(), slice.len())
+    }
+}
@@ -0,0 +1,113 @@
+//! Slice operations on `As{,Mut}Slice` types
+
+use core::slice::{Chunks, ChunksMut, Iter, IterMut, Windows};
+
+use {AsMutSlice, AsSlice};
+
+/// Slice operations available on all the `AsSlice` types
+///
+/// These methods forward to the methods of the same name on `[T]`
+pub trait AsSliceExt: AsSlice {
+    /// Returns the number of elements in the slice view
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns `true` if the slice view has a length of 0
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns an iterator over the slice view
+    fn iter(&self) -> Iter<'_, Self::Element> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice view at a time
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0
+    fn chunks(&self, chunk_size: usize) -> Chunks<'_, Self::Element> {
+        self.as_slice().chunks(chunk_size)
+    }
+
+    /// Returns an iterator over all the contiguous windows of length `size` of the slice view
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0
+    fn windows(&self, size: usize) -> Windows<'_, Self::Element> {
+        self.as_slice().windows(size)
+    }
+
+    /// Divides the slice view into two at an index
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`
+    fn split_at(&self, mid: usize) -> (&[Self::Element], &[Self::Element]) {
+        self.as_slice().split_at(mid)
+    }
+
+    /// Returns `true` if the slice view contains an element with the given value
+    fn contains(&self, x: &Self::Element) -> bool
+    where
+        Self::Element: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+}
+
+/// Slice operations available on all the `AsMutSlice` types
+///
+/// These methods forward to the methods of the same name on `[T]`
+pub trait AsMutSliceExt: AsMutSlice {
+    /// Returns an iterator that allows modifying each element of the slice view
+    fn iter_mut(&mut self) -> IterMut<'_, Self::Element> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice view at a time
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0
+    fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, Self::Element> {
+        self.as_mut_slice().chunks_mut(chunk_size)
+    }
+
+    /// Divides the mutable slice view into two at an index
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`
+    fn split_at_mut(&mut self, mid: usize) -> (&mut [Self::Element], &mut [Self::Element]) {
+        self.as_mut_slice().split_at_mut(mid)
+    }
+
+    /// Copies all the elements of `src` into the slice view
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `self` have different lengths
+    fn copy_from<S>(&mut self, src: &S)
+    where
+        S: ?Sized + AsSlice<Element = Self::Element>,
+        Self::Element: Copy,
+    {
+        self.as_mut_slice().copy_from_slice(src.as_slice())
+    }
+
+    /// Fills the slice view with clones of `value`
+    fn fill(&mut self, value: Self::Element)
+    where
+        Self::Element: Clone,
+    {
+        self.as_mut_slice().fill(value)
+    }
+}
+
+impl<S> AsSliceExt for S where S: ?Sized + AsSlice {}
+
+impl<S> AsMutSliceExt for S where S: ?Sized + AsMutSlice {}
@@ -0,0 +1,228 @@
+//! Flat views of slices of arrays, and the inverse chunked views
+
+use core::slice;
+
+use {AsMutSlice, AsSlice};
+
+/// Flat immutable view of an `AsSlice` type whose elements are arrays
+///
+/// This trait is implemented for all the `AsSlice<Element = [T; M]>` types
+pub trait AsFlatSlice {
+    /// The element type of the arrays
+    type Scalar;
+
+    /// Returns the immutable slice view of `Self` with the arrays concatenated
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the flat view overflows `usize`, which can only happen when
+    /// `Scalar` is zero sized
+    fn as_flat_slice(&self) -> &[Self::Scalar];
+}
+
+/// Flat mutable view of an `AsMutSlice` type whose elements are arrays
+///
+/// This trait is implemented for all the `AsMutSlice<Element = [T; M]>` types
+pub trait AsMutFlatSlice: AsFlatSlice {
+    /// Returns the mutable slice view of `Self` with the arrays concatenated
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the flat view overflows `usize`, which can only happen when
+    /// `Scalar` is zero sized
+    fn as_mut_flat_slice(&mut self) -> &mut [Self::Scalar];
+}
+
+/// Chunked immutable view of an `AsSlice` type
+///
+/// This trait is implemented for all the `AsSlice` types
+pub trait TryAsChunks: AsSlice {
+    /// Returns the immutable slice view of `Self` as arrays of `M` elements, or `None` if the
+    /// length of the slice view is not a multiple of `M` or if `M` is 0
+    fn try_as_chunks<const M: usize>(&self) -> Option<&[[Self::Element; M]]>;
+}
+
+/// Chunked mutable view of an `AsMutSlice` type
+///
+/// This trait is implemented for all the `AsMutSlice` types
+pub trait TryAsMutChunks: TryAsChunks + AsMutSlice {
+    /// Returns the mutable slice view of `Self` as arrays of `M` elements, or `None` if the
+    /// length of the slice view is not a multiple of `M` or if `M` is 0
+    fn try_as_mut_chunks<const M: usize>(&mut self) -> Option<&mut [[Self::Element; M]]>;
+}
+
+impl<S, T, const M: usize> AsFlatSlice for S
+where
+    S: ?Sized + AsSlice<Element = [T; M]>,
+{
+    type Scalar = T;
+
+    fn as_flat_slice(&self) -> &[T] {
+        let slice = self.as_slice();
+        let len = flat_len::<M>(slice.len());
+
+        // NOTE(unsafe) arrays are laid out contiguously, without padding between elements
+        unsafe { slice::from_raw_parts(slice.as_ptr() as *const T, len) }
+    }
+}
+
+impl<S, T, const M: usize> AsMutFlatSlice for S
+where
+    S: ?Sized + AsMutSlice<Element = [T; M]>,
+{
+    fn as_mut_flat_slice(&mut self) -> &mut [T] {
+        let slice = self.as_mut_slice();
+        let len = flat_len::<M>(slice.len());
+
+        // NOTE(unsafe) arrays are laid out contiguously, without padding between elements
+        unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, len) }
+    }
+}
+
+impl<S> TryAsChunks for S
+where
+    S: ?Sized + AsSlice,
+{
+    fn try_as_chunks<const M: usize>(&self) -> Option<&[[S::Element; M]]> {
+        let slice = self.as_slice();
+
+        if M == 0 || slice.len() % M != 0 {
+            return None;
+        }
+
+        // NOTE(unsafe) `[T; M]` has the same layout as `M` contiguous `T`s
+        unsafe {
+            Some(slice::from_raw_parts(
+                slice.as_ptr() as *const [S::Element; M],
+                slice.len() / M,
+            ))
+        }
+    }
+}
+
+impl<S> TryAsMutChunks for S
+where
+    S: ?Sized + AsMutSlice,
+{
+    fn try_as_mut_chunks<const M: usize>(&mut self) -> Option<&mut [[S::Element; M]]> {
+        let slice = self.as_mut_slice();
+
+        if M == 0 || slice.len() % M != 0 {
+            return None;
+        }
+
+        // NOTE(unsafe) `[T; M]` has the same layout as `M` contiguous `T`s
+        unsafe {
+            Some(slice::from_raw_parts_mut(
+                slice.as_mut_ptr() as *mut [S::Element; M],
+                slice.len() / M,
+            ))
+        }
+    }
+}
+
+/// Adapter that presents an `As{,Mut}Slice<Element = [T; M]>` buffer as an `As{,Mut}Slice` of `T`
+///
+/// The slice views panic in the same (zero sized `T`) cases as `AsFlatSlice::as_flat_slice`
+///
+/// # Example
+///
+/// ```
+/// use as_slice::{AsSlice, Flatten};
+///
+/// fn checksum<B>(buffer: &B) -> u8
+/// where
+///     B: AsSlice<Element = u8>,
+/// {
+///     buffer.as_slice().iter().fold(0, |acc, b| acc ^ b)
+/// }
+///
+/// let descriptors = [[1u8, 2, 3, 4], [5, 6, 7, 8]];
+///
+/// assert_eq!(checksum(&Flatten::new(descriptors)), 8);
+/// ```
+pub struct Flatten<B>
+where
+    B: AsFlatSlice,
+{
+    buffer: B,
+}
+
+impl<B> Flatten<B>
+where
+    B: AsFlatSlice,
+{
+    /// Wraps `buffer`
+    pub fn new(buffer: B) -> Self {
+        Flatten { buffer }
+    }
+
+    /// Releases the underlying buffer
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B> AsSlice for Flatten<B>
+where
+    B: AsFlatSlice,
+{
+    type Element = B::Scalar;
+
+    fn as_slice(&self) -> &[B::Scalar] {
+        self.buffer.as_flat_slice()
+    }
+}
+
+impl<B> AsMutSlice for Flatten<B>
+where
+    B: AsMutFlatSlice,
+{
+    fn as_mut_slice(&mut self) -> &mut [B::Scalar] {
+        self.buffer.as_mut_flat_slice()
+    }
+}
+
+/// Returns the number of scalars in `len` arrays of `M` scalars
+///
+/// # Panics
+///
+/// Panics if the result overflows `usize`, which can only happen when the scalar is zero sized as
+/// the arrays are otherwise already in memory
+fn flat_len<const M: usize>(len: usize) -> usize {
+    len.checked_mul(M)
+        .expect("flat slice length overflows `usize`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsFlatSlice, AsMutFlatSlice, TryAsChunks};
+    use AsSlice2D;
+
+    #[test]
+    fn flat_views() {
+        let mut grid = [[1u16, 2, 3], [4, 5, 6]];
+
+        assert_eq!(grid.as_flat_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(grid.as_flat(), grid.as_flat_slice());
+
+        grid.as_mut_flat_slice()[3] = 7;
+        assert_eq!(grid, [[1, 2, 3], [7, 5, 6]]);
+        assert_eq!(grid.as_flat_slice().try_as_chunks::<3>(), Some(&grid[..]));
+    }
+
+    #[test]
+    fn zero_sized() {
+        let units = [[(); 4]; 3];
+
+        assert_eq!(units.as_flat_slice().len(), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "flat slice length overflows `usize`")]
+    fn zero_sized_overflow() {
+        let units = [[(); usize::MAX]; 2];
+
+        units.as_flat_slice();
+    }
+}
@@ -0,0 +1,166 @@
+//! `core::fmt::Write` adapter over byte buffers
+//!
+//! # Example
+//!
+//! ```
+//! use std::fmt::Write;
+//!
+//! use as_slice::fmt::{FmtBuffer, Overflow};
+//!
+//! let mut line = FmtBuffer::new([0u8; 16]);
+//! write!(line, "t={}ms", 1234).unwrap();
+//! assert_eq!(line.as_str(), "t=1234ms");
+//!
+//! assert!(write!(line, " temperature={}", -12.5).is_err());
+//! assert_eq!(line.as_str(), "t=1234ms");
+//!
+//! let mut line = FmtBuffer::with_overflow([0u8; 8], Overflow::Truncate);
+//! write!(line, "t={}ms", 123456).unwrap();
+//! assert_eq!(line.as_str(), "t=123456");
+//! assert!(line.is_truncated());
+//! ```
+
+use core::fmt;
+use core::str;
+
+use {AsMutSlice, AsSlice, AsStr};
+
+/// What to do when a string doesn't fit in the remaining space of a `FmtBuffer`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Overflow {
+    /// Write none of the string and return `fmt::Error`
+    ///
+    /// NOTE this applies to each `write_str` call; a single `write!` may make several of them so
+    /// the pieces that fit before the overflow are still written
+    Error,
+    /// Write as many characters as fit and discard the rest
+    Truncate,
+}
+
+/// A `core::fmt::Write` implementer that writes into any `AsMutSlice<Element = u8>` buffer
+///
+/// The written bytes are always valid UTF-8 and can be retrieved with `as_str`. `FmtBuffer` also
+/// implements `AsStr`; its slice view is the written portion of the buffer.
+pub struct FmtBuffer<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    buffer: B,
+    len: usize,
+    overflow: Overflow,
+    truncated: bool,
+}
+
+impl<B> FmtBuffer<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    /// Creates an empty `FmtBuffer` that uses the `Overflow::Error` policy
+    pub fn new(buffer: B) -> Self {
+        FmtBuffer::with_overflow(buffer, Overflow::Error)
+    }
+
+    /// Creates an empty `FmtBuffer` that uses the given `overflow` policy
+    pub fn with_overflow(buffer: B, overflow: Overflow) -> Self {
+        FmtBuffer {
+            buffer,
+            len: 0,
+            overflow,
+            truncated: false,
+        }
+    }
+
+    /// Returns the written portion of the buffer
+    pub fn as_str(&self) -> &str {
+        let bytes = &self.buffer.as_slice()[..self.len];
+
+        // NOTE only whole UTF-8 sequences are ever written but `B` could hand out different memory
+        // on each `as_slice` call so we validate instead of using `from_utf8_unchecked`
+        str::from_utf8(bytes)
+            .unwrap_or_else(|e| str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or(""))
+    }
+
+    /// Returns the number of bytes written
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes that can still be written
+    pub fn remaining(&self) -> usize {
+        self.buffer.as_slice().len() - self.len
+    }
+
+    /// Returns `true` if any output has been discarded by the `Overflow::Truncate` policy
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Discards the written contents
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+
+    /// Releases the underlying buffer
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B> fmt::Write for FmtBuffer<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining = self.remaining();
+
+        let n = if s.len() <= remaining {
+            s.len()
+        } else {
+            match self.overflow {
+                Overflow::Error => return Err(fmt::Error),
+                Overflow::Truncate => {
+                    self.truncated = true;
+
+                    // don't split a multi-byte character
+                    let mut n = remaining;
+                    while !s.is_char_boundary(n) {
+                        n -= 1;
+                    }
+                    n
+                }
+            }
+        };
+
+        let start = self.len;
+        self.len += n;
+        self.buffer.as_mut_slice()[start..self.len].copy_from_slice(&s.as_bytes()[..n]);
+
+        Ok(())
+    }
+}
+
+impl<B> AsSlice for FmtBuffer<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    type Element = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl<B> AsStr for FmtBuffer<B>
+where
+    B: AsMutSlice<Element = u8>,
+{
+    fn as_str(&self) -> &str {
+        FmtBuffer::as_str(self)
+    }
+}
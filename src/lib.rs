@@ -3,21 +3,143 @@
 //! These traits are somewhat similar to the `AsRef` and `AsMut` except that they are **NOT**
 //! polymorphic (no input type parameter) and their methods always return slices (`[T]`).
 //!
+//! The `AsStr` and `AsMutStr` traits are the UTF-8 counterparts of these: they always return
+//! string slices (`str`). Every `AsStr` type is also `AsSlice<Element = u8>`.
+//!
+//! The `AsSliceExt` and `AsMutSliceExt` extension traits make the most common slice operations
+//! (`len`, `iter`, `split_at`, `copy_from`, etc.) directly available on any `As{,Mut}Slice` type.
+//!
+//! `Ring` is a ring buffer that can use any `AsMutSlice` type as storage, and the [`cursor`]
+//! module provides a cursor for (de)serializing integers and floats into byte buffers. The
+//! [`fmt`] module lets `core::fmt` machinery write into byte buffers.
+//!
+//! The `AsByteSlice` and `AsMutByteSlice` traits view any `AsSlice` whose `Element` is plain old
+//! data (integers, floats and arrays of them) as a byte slice (`[u8]`). The [`cast`] module
+//! extends this to checked views as slices of any other plain-old-data type.
+//!
+//! The `AsArray` and `AsMutArray` traits are the fixed size counterparts of `As{,Mut}Slice`: their
+//! methods return arrays (`[T; N]`) so the length is known at compile time. `TryAsArray` and
+//! `TryAsMutArray` provide fallible array views of any `As{,Mut}Slice` type.
+//!
+//! The `AsSlice2D` and `AsMutSlice2D` traits are two dimensional (rows and columns) views of
+//! buffers, implemented for nested arrays and for `Strided`, a wrapper over flat storage.
+//!
+//! `AsFlatSlice` and `AsMutFlatSlice` view any `As{,Mut}Slice<Element = [T; M]>` as a slice of `T`
+//! (`Flatten` wraps such a buffer into an `As{,Mut}Slice<Element = T>`), and `TryAsChunks` and
+//! `TryAsMutChunks` do the inverse.
+//!
+//! The `AsSliceList` and `AsMutSliceList` traits represent sequences of discontiguous slices, e.g.
+//! for vectored (scatter-gather) I/O. `Concat` presents two buffers as a single sequence.
+//!
+//! `OwnedBuffer` takes ownership of a `StableDeref + DerefMut` buffer whose target is
+//! `AsMutSlice` and exposes the target's memory as a raw pointer / length pair for the duration of
+//! a transfer, after which the original buffer can be reclaimed with its concrete type. The [`dma`] module provides the `ReadBuffer` and
+//! `WriteBuffer` traits HALs can use to bound their DMA APIs. `SliceRange` restricts any of these
+//! buffers to a sub-range while keeping ownership of the whole buffer, and the [`split`] module
+//! splits them into two independently owned halves. `VolatileSlice` only accesses the memory of
+//! such a buffer with volatile reads and writes.
+//!
 //! The main use case of these traits is writing generic code that accepts (fixed size) buffers. For
 //! example, a bound `T: StableDeref + AsMutSlice<Element = u8> + 'static` will accepts types like
 //! `&'static mut [u8]` and `&'static mut [u8; 128]` -- all
 //! of them are appropriate for DMA transfers.
 //!
+//! # Optional features
+//!
+//! - `alloc`: implements the traits for `Vec<T>`, `Box<[T]>`, `Box<[T; N]>`, `Rc<[T]>`,
+//!   `Arc<[T]>` and `Cow<[T]>`. `Rc` and `Arc` only implement `AsSlice` as they can't hand out
+//!   mutable references to their contents. Also implements `AsStr` and `AsMutStr` for `String`.
+//! - `arrayvec`: implements the traits for `arrayvec::ArrayVec<T, CAP>`, and `AsStr` and
+//!   `AsMutStr` for `arrayvec::ArrayString<CAP>`.
+//! - `derive`: provides `#[derive(AsSlice, AsMutSlice)]` for structs that wrap a buffer. See the
+//!   `as-slice-derive` crate for details.
+//! - `embedded-io`, `embedded-io-async`: implements the `embedded-io` (v0.6) `Read`, `BufRead` and
+//!   `Write` traits, and their async counterparts, for [`cursor::Cursor`].
+//! - `generic-array-0_14`, `generic-array-1`: implements the traits for `GenericArray<T, N>` from
+//!   the corresponding version of the `generic-array` crate.
+//! - `heapless`: implements the traits for `heapless::Vec<T, N>`, and `AsStr` and `AsMutStr` for
+//!   `heapless::String<N>`. The latter doesn't implement `AsMutSlice` as that would let callers
+//!   break its UTF-8 invariant.
+//! - `serde`: enables the [`serde`](serde/index.html) module with helpers for (de)serializing
+//!   buffers.
+//! - `smallvec`: implements the traits for `smallvec::SmallVec<A>`.
+//! - `std`: implements the `std::io` `Read`, `BufRead`, `Write` and `Seek` traits for
+//!   [`cursor::Cursor`], and `std::error::Error` for this crate's error types. Implies `alloc`.
+//!
 //! # Minimal Supported Rust Version (MSRV)
 //!
 //! This crate is guaranteed to compile on stable Rust 1.51 and up. It *might* compile on older
 //! versions but that may change in any new patch release.
+//!
+//! Some optional features depend on crates that require a newer Rust version; enabling them raises
+//! the MSRV to that of the dependency.
 
+#![allow(clippy::needless_lifetimes)]
 #![deny(missing_docs)]
 #![deny(warnings)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "arrayvec")]
+extern crate arrayvec;
+#[cfg(feature = "derive")]
+extern crate as_slice_derive;
+#[cfg(feature = "embedded-io")]
+extern crate embedded_io_0_6;
+#[cfg(feature = "embedded-io-async")]
+extern crate embedded_io_async_0_6;
+// NOTE generic-array 0.14.8+ deprecates all of its items in favor of 1.x
+#[allow(deprecated, clippy::useless_attribute)]
+#[cfg(feature = "generic-array-0_14")]
+extern crate generic_array_0_14;
+#[cfg(feature = "generic-array-1")]
+extern crate generic_array_1;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
 extern crate stable_deref_trait;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+mod array;
+mod bytes;
+pub mod cast;
+mod concat;
+pub mod cursor;
+pub mod dma;
+mod ext;
+mod flatten;
+pub mod fmt;
+mod list;
+mod owned;
+mod range;
+mod ring;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod slice2d;
+pub mod split;
+mod volatile;
+
+pub use array::{AsArray, AsMutArray, TryAsArray, TryAsMutArray};
+#[cfg(feature = "derive")]
+pub use as_slice_derive::{AsMutSlice, AsSlice};
+pub use bytes::{AsByteSlice, AsMutByteSlice, Pod};
+pub use concat::{Concat, ConcatChunks};
+pub use ext::{AsMutSliceExt, AsSliceExt};
+pub use flatten::{AsFlatSlice, AsMutFlatSlice, Flatten, TryAsChunks, TryAsMutChunks};
+pub use list::{AsMutSliceList, AsSliceList, Segments};
+pub use owned::{IntoSlice, OwnedBuffer};
+pub use range::SliceRange;
+pub use ring::Ring;
+pub use slice2d::{AsMutSlice2D, AsSlice2D, Strided};
+pub use volatile::VolatileSlice;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc, vec::Vec};
 
 /// Something that can be seen as an immutable slice
 pub trait AsSlice {
@@ -34,6 +156,18 @@ pub trait AsMutSlice: AsSlice {
     fn as_mut_slice(&mut self) -> &mut [Self::Element];
 }
 
+/// Something that can be seen as an immutable string slice
+pub trait AsStr: AsSlice<Element = u8> {
+    /// Returns the immutable string slice view of `Self`
+    fn as_str(&self) -> &str;
+}
+
+/// Something that can be seen as an mutable string slice
+pub trait AsMutStr: AsStr {
+    /// Returns the mutable string slice view of `Self`
+    fn as_mut_str(&mut self) -> &mut str;
+}
+
 impl<'a, S> AsSlice for &'a S
 where
     S: ?Sized + AsSlice,
@@ -92,3 +226,511 @@ impl<T, const N: usize> AsMutSlice for [T; N] {
         self
     }
 }
+
+impl<'a, S> AsStr for &'a S
+where
+    S: ?Sized + AsStr,
+{
+    fn as_str(&self) -> &str {
+        (**self).as_str()
+    }
+}
+
+impl<'a, S> AsStr for &'a mut S
+where
+    S: ?Sized + AsStr,
+{
+    fn as_str(&self) -> &str {
+        (**self).as_str()
+    }
+}
+
+impl<'a, S> AsMutStr for &'a mut S
+where
+    S: ?Sized + AsMutStr,
+{
+    fn as_mut_str(&mut self) -> &mut str {
+        (**self).as_mut_str()
+    }
+}
+
+impl AsSlice for str {
+    type Element = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsStr for str {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl AsMutStr for str {
+    fn as_mut_str(&mut self) -> &mut str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSlice for Vec<T> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsMutSlice for Vec<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSlice for Box<[T]> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsMutSlice for Box<[T]> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> AsSlice for Box<[T; N]> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        &**self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> AsMutSlice for Box<[T; N]> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut **self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSlice for Rc<[T]> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSlice for Arc<[T]> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> AsSlice for Cow<'a, [T]>
+where
+    T: Clone,
+{
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+/// NOTE this clones the borrowed data into a `Vec` if `self` is `Cow::Borrowed`
+#[cfg(feature = "alloc")]
+impl<'a, T> AsMutSlice for Cow<'a, [T]>
+where
+    T: Clone,
+{
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.to_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsSlice for String {
+    type Element = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsStr for String {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsMutStr for String {
+    fn as_mut_str(&mut self) -> &mut str {
+        self
+    }
+}
+
+#[allow(deprecated)]
+#[cfg(feature = "generic-array-0_14")]
+impl<T, N> AsSlice for generic_array_0_14::GenericArray<T, N>
+where
+    N: generic_array_0_14::ArrayLength<T>,
+{
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[allow(deprecated)]
+#[cfg(feature = "generic-array-0_14")]
+impl<T, N> AsMutSlice for generic_array_0_14::GenericArray<T, N>
+where
+    N: generic_array_0_14::ArrayLength<T>,
+{
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(feature = "generic-array-1")]
+impl<T, N> AsSlice for generic_array_1::GenericArray<T, N>
+where
+    N: generic_array_1::ArrayLength,
+{
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "generic-array-1")]
+impl<T, N> AsMutSlice for generic_array_1::GenericArray<T, N>
+where
+    N: generic_array_1::ArrayLength,
+{
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> AsSlice for heapless::Vec<T, N> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> AsMutSlice for heapless::Vec<T, N> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> AsSlice for heapless::String<N> {
+    type Element = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> AsStr for heapless::String<N> {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> AsMutStr for heapless::String<N> {
+    fn as_mut_str(&mut self) -> &mut str {
+        self
+    }
+}
+
+/// # Example
+///
+/// ```
+/// extern crate arrayvec;
+/// extern crate as_slice;
+///
+/// use arrayvec::ArrayVec;
+/// use as_slice::AsMutSlice;
+///
+/// fn zero<B>(mut buffer: B) -> B
+/// where
+///     B: AsMutSlice<Element = u8>,
+/// {
+///     for byte in buffer.as_mut_slice() {
+///         *byte = 0;
+///     }
+///     buffer
+/// }
+///
+/// let mut packet = ArrayVec::<u8, 64>::new();
+/// packet.push(0xde);
+/// packet.push(0xad);
+///
+/// assert_eq!(&zero(packet)[..], &[0, 0]);
+/// ```
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> AsSlice for arrayvec::ArrayVec<T, CAP> {
+    type Element = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> AsMutSlice for arrayvec::ArrayVec<T, CAP> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+/// # Example
+///
+/// ```
+/// extern crate arrayvec;
+/// extern crate as_slice;
+///
+/// use arrayvec::ArrayString;
+/// use as_slice::AsSlice;
+///
+/// fn checksum<B>(buffer: &B) -> u8
+/// where
+///     B: AsSlice<Element = u8> + ?Sized,
+/// {
+///     buffer.as_slice().iter().fold(0, |acc, b| acc.wrapping_add(*b))
+/// }
+///
+/// let s = ArrayString::<16>::from("AT").unwrap();
+///
+/// assert_eq!(checksum(&s), b'A' + b'T');
+/// ```
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> AsSlice for arrayvec::ArrayString<CAP> {
+    type Element = u8;
+
+    fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> AsStr for arrayvec::ArrayString<CAP> {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> AsMutStr for arrayvec::ArrayString<CAP> {
+    fn as_mut_str(&mut self) -> &mut str {
+        self
+    }
+}
+
+/// # Example
+///
+/// ```
+/// extern crate as_slice;
+/// extern crate smallvec;
+///
+/// use as_slice::AsSlice;
+/// use smallvec::SmallVec;
+///
+/// fn sum<B>(buffer: &B) -> u32
+/// where
+///     B: AsSlice<Element = u32> + ?Sized,
+/// {
+///     buffer.as_slice().iter().sum()
+/// }
+///
+/// let mut v = SmallVec::<[u32; 2]>::new();
+/// v.extend_from_slice(&[1, 2, 3]); // spills onto the heap
+///
+/// assert_eq!(sum(&v), 6);
+/// ```
+#[cfg(feature = "smallvec")]
+impl<A> AsSlice for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+{
+    type Element = A::Item;
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A> AsMutSlice for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+{
+    fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    mod alloc_impls {
+        use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc, vec, vec::Vec};
+
+        use {AsMutSlice, AsMutStr, AsSlice, AsStr};
+
+        fn sum<S>(buffer: &S) -> u32
+        where
+            S: ?Sized + AsSlice<Element = u32>,
+        {
+            buffer.as_slice().iter().sum()
+        }
+
+        fn increment<S>(buffer: &mut S)
+        where
+            S: ?Sized + AsMutSlice<Element = u32>,
+        {
+            buffer.as_mut_slice().iter_mut().for_each(|x| *x += 1);
+        }
+
+        #[test]
+        fn vec() {
+            let mut v: Vec<u32> = vec![1, 2, 3];
+
+            assert_eq!(sum(&v), 6);
+            increment(&mut v);
+            assert_eq!(v, [2, 3, 4]);
+        }
+
+        #[test]
+        fn boxed_slice() {
+            let mut b: Box<[u32]> = Box::new([1, 2, 3]);
+
+            assert_eq!(sum(&b), 6);
+            increment(&mut b);
+            assert_eq!(&*b, &[2, 3, 4]);
+        }
+
+        #[test]
+        fn boxed_array() {
+            let mut b: Box<[u32; 3]> = Box::new([1, 2, 3]);
+
+            assert_eq!(sum(&b), 6);
+            increment(&mut b);
+            assert_eq!(*b, [2, 3, 4]);
+        }
+
+        #[test]
+        fn rc_and_arc() {
+            let rc: Rc<[u32]> = Rc::from(&[1, 2, 3][..]);
+            let arc: Arc<[u32]> = Arc::from(&[4, 5][..]);
+
+            assert_eq!(sum(&rc), 6);
+            assert_eq!(sum(&arc), 9);
+        }
+
+        #[test]
+        fn cow_borrowed() {
+            let data = [1, 2, 3];
+            let mut cow = Cow::Borrowed(&data[..]);
+
+            assert_eq!(sum(&cow), 6);
+
+            // `as_mut_slice` clones the borrowed data
+            increment(&mut cow);
+            match cow {
+                Cow::Owned(ref v) => assert_eq!(*v, [2, 3, 4]),
+                Cow::Borrowed(_) => panic!("`as_mut_slice` didn't clone the borrowed data"),
+            }
+            assert_eq!(data, [1, 2, 3]);
+        }
+
+        #[test]
+        fn cow_owned() {
+            let mut cow: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+            let ptr = cow.as_slice().as_ptr();
+
+            assert_eq!(sum(&cow), 6);
+
+            // `as_mut_slice` reuses the owned data
+            increment(&mut cow);
+            assert_eq!(cow.as_slice().as_ptr(), ptr);
+            assert_eq!(&*cow, &[2, 3, 4]);
+        }
+
+        #[test]
+        fn string() {
+            let mut s = String::from("abc");
+
+            assert_eq!(s.as_slice(), b"abc");
+            assert_eq!(AsStr::as_str(&s), "abc");
+            AsMutStr::as_mut_str(&mut s).make_ascii_uppercase();
+            assert_eq!(s, "ABC");
+        }
+    }
+
+    #[cfg(any(feature = "generic-array-0_14", feature = "generic-array-1"))]
+    mod generic_array_impls {
+        use AsMutSlice;
+
+        // takes the buffer by value so `&mut GenericArray` must go through the `&'a mut S` impl
+        fn fill<B>(mut buffer: B, value: u8) -> usize
+        where
+            B: AsMutSlice<Element = u8>,
+        {
+            let slice = buffer.as_mut_slice();
+            slice.iter_mut().for_each(|x| *x = value);
+            slice.len()
+        }
+
+        #[cfg(feature = "generic-array-0_14")]
+        #[allow(deprecated)]
+        #[test]
+        fn generic_array_0_14() {
+            use generic_array_0_14::{typenum::U32, GenericArray};
+
+            let mut array = GenericArray::<u8, U32>::default();
+
+            assert_eq!(fill(&mut array, 1), 32);
+            assert!(array.iter().all(|x| *x == 1));
+        }
+
+        #[cfg(feature = "generic-array-1")]
+        #[test]
+        fn generic_array_1() {
+            use generic_array_1::{typenum::U32, GenericArray};
+
+            let mut array = GenericArray::<u8, U32>::default();
+
+            assert_eq!(fill(&mut array, 1), 32);
+            assert!(array.iter().all(|x| *x == 1));
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Sequences of discontiguous slices (scatter-gather lists)
+
+use core::cmp;
+
+use {AsMutSlice, AsSlice};
+
+/// Something that can be seen as a sequence of immutable slices
+///
+/// This is implemented for tuples (up to 12 elements) and arrays of `AsSlice` types, and for
+/// slices of `AsSlice` types (e.g. `[&[u8]]`)
+///
+/// # Example
+///
+/// ```
+/// use as_slice::AsSliceList;
+///
+/// let header = [0xaa, 0x55];
+/// let payload = [1, 2, 3];
+/// let list = (&header, &payload[..]);
+///
+/// assert_eq!(list.total_len(), 5);
+///
+/// let mut packet = [0; 8];
+/// assert_eq!(list.gather_into(&mut packet), 5);
+/// assert_eq!(packet, [0xaa, 0x55, 1, 2, 3, 0, 0, 0]);
+/// ```
+pub trait AsSliceList {
+    /// The element type of the slices
+    type Element;
+
+    /// Returns the number of slices in the sequence
+    fn segment_count(&self) -> usize;
+
+    /// Returns the `i`-th slice of the sequence, or `None` if `i >= segment_count`
+    fn segment(&self, i: usize) -> Option<&[Self::Element]>;
+
+    /// Returns an iterator over the slices of the sequence
+    fn segments(&self) -> Segments<'_, Self> {
+        Segments {
+            list: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the sum of the lengths of all the slices
+    fn total_len(&self) -> usize {
+        self.segments().map(|segment| segment.len()).sum()
+    }
+
+    /// Copies the elements of the slices, in order, into `dst` until either runs out and returns
+    /// the number of elements copied
+    fn gather_into<S>(&self, dst: &mut S) -> usize
+    where
+        S: ?Sized + AsMutSlice<Element = Self::Element>,
+        Self::Element: Copy,
+    {
+        let dst = dst.as_mut_slice();
+        let mut n = 0;
+
+        for segment in self.segments() {
+            let k = cmp::min(segment.len(), dst.len() - n);
+            dst[n..n + k].copy_from_slice(&segment[..k]);
+            n += k;
+        }
+
+        n
+    }
+}
+
+/// Something that can be seen as a sequence of mutable slices
+pub trait AsMutSliceList: AsSliceList {
+    /// Returns the `i`-th slice of the sequence, or `None` if `i >= segment_count`
+    fn segment_mut(&mut self, i: usize) -> Option<&mut [Self::Element]>;
+
+    /// Copies the elements of `src` into the slices, in order, until either runs out and returns
+    /// the number of elements copied
+    fn scatter_from<S>(&mut self, src: &S) -> usize
+    where
+        S: ?Sized + AsSlice<Element = Self::Element>,
+        Self::Element: Copy,
+    {
+        let src = src.as_slice();
+        let mut n = 0;
+
+        for i in 0..self.segment_count() {
+            if let Some(segment) = self.segment_mut(i) {
+                let k = cmp::min(segment.len(), src.len() - n);
+                segment[..k].copy_from_slice(&src[n..n + k]);
+                n += k;
+            }
+        }
+
+        n
+    }
+}
+
+/// Iterator over the slices of an `AsSliceList`
+pub struct Segments<'a, L>
+where
+    L: ?Sized,
+{
+    list: &'a L,
+    index: usize,
+}
+
+impl<'a, L> Iterator for Segments<'a, L>
+where
+    L: ?Sized + AsSliceList,
+{
+    type Item = &'a [L::Element];
+
+    fn next(&mut self) -> Option<&'a [L::Element]> {
+        let segment = self.list.segment(self.index)?;
+        self.index += 1;
+        Some(segment)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.list.segment_count().saturating_sub(self.index);
+        (n, Some(n))
+    }
+}
+
+impl<'a, L> AsSliceList for &'a L
+where
+    L: ?Sized + AsSliceList,
+{
+    type Element = L::Element;
+
+    fn segment_count(&self) -> usize {
+        (**self).segment_count()
+    }
+
+    fn segment(&self, i: usize) -> Option<&[L::Element]> {
+        (**self).segment(i)
+    }
+}
+
+impl<'a, L> AsSliceList for &'a mut L
+where
+    L: ?Sized + AsSliceList,
+{
+    type Element = L::Element;
+
+    fn segment_count(&self) -> usize {
+        (**self).segment_count()
+    }
+
+    fn segment(&self, i: usize) -> Option<&[L::Element]> {
+        (**self).segment(i)
+    }
+}
+
+impl<'a, L> AsMutSliceList for &'a mut L
+where
+    L: ?Sized + AsMutSliceList,
+{
+    fn segment_mut(&mut self, i: usize) -> Option<&mut [L::Element]> {
+        (**self).segment_mut(i)
+    }
+}
+
+impl<S> AsSliceList for [S]
+where
+    S: AsSlice,
+{
+    type Element = S::Element;
+
+    fn segment_count(&self) -> usize {
+        self.len()
+    }
+
+    fn segment(&self, i: usize) -> Option<&[S::Element]> {
+        self.get(i).map(|s| s.as_slice())
+    }
+}
+
+impl<S> AsMutSliceList for [S]
+where
+    S: AsMutSlice,
+{
+    fn segment_mut(&mut self, i: usize) -> Option<&mut [S::Element]> {
+        self.get_mut(i).map(|s| s.as_mut_slice())
+    }
+}
+
+impl<S, const N: usize> AsSliceList for [S; N]
+where
+    S: AsSlice,
+{
+    type Element = S::Element;
+
+    fn segment_count(&self) -> usize {
+        N
+    }
+
+    fn segment(&self, i: usize) -> Option<&[S::Element]> {
+        self.get(i).map(|s| s.as_slice())
+    }
+}
+
+impl<S, const N: usize> AsMutSliceList for [S; N]
+where
+    S: AsMutSlice,
+{
+    fn segment_mut(&mut self, i: usize) -> Option<&mut [S::Element]> {
+        self.get_mut(i).map(|s| s.as_mut_slice())
+    }
+}
+
+macro_rules! tuple {
+    ($len:expr; $A:ident: $a:tt $(, $T:ident: $i:tt)*) => {
+        impl<$A, $($T),*> AsSliceList for ($A, $($T,)*)
+        where
+            $A: AsSlice,
+            $($T: AsSlice<Element = $A::Element>,)*
+        {
+            type Element = $A::Element;
+
+            fn segment_count(&self) -> usize {
+                $len
+            }
+
+            fn segment(&self, i: usize) -> Option<&[$A::Element]> {
+                match i {
+                    $a => Some(self.$a.as_slice()),
+                    $($i => Some(self.$i.as_slice()),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl<$A, $($T),*> AsMutSliceList for ($A, $($T,)*)
+        where
+            $A: AsMutSlice,
+            $($T: AsMutSlice<Element = $A::Element>,)*
+        {
+            fn segment_mut(&mut self, i: usize) -> Option<&mut [$A::Element]> {
+                match i {
+                    $a => Some(self.$a.as_mut_slice()),
+                    $($i => Some(self.$i.as_mut_slice()),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+tuple!(1; A: 0);
+tuple!(2; A: 0, B: 1);
+tuple!(3; A: 0, B: 1, C: 2);
+tuple!(4; A: 0, B: 1, C: 2, D: 3);
+tuple!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+tuple!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+tuple!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+tuple!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+tuple!(9; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+tuple!(10; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+tuple!(11; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+tuple!(12; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
+#[cfg(test)]
+mod tests {
+    use {AsMutSliceExt, AsMutSliceList, AsSliceList};
+
+    #[test]
+    fn gather_scatter() {
+        let mut list = [[0u8; 2]; 3];
+
+        assert_eq!(list.scatter_from(&[1, 2, 3, 4, 5]), 5);
+        assert_eq!(list, [[1, 2], [3, 4], [5, 0]]);
+
+        let mut dst = [0; 4];
+        assert_eq!(list.gather_into(&mut dst), 4);
+        assert_eq!(dst, [1, 2, 3, 4]);
+    }
+
+    // `AsMutSliceExt::copy_from` and the list methods are both available on arrays of arrays
+    #[test]
+    fn no_clash_with_ext() {
+        let mut list = [[0u8; 2]; 2];
+
+        list.copy_from(&[[1, 2], [3, 4]]);
+        assert_eq!(list.total_len(), 4);
+        assert_eq!(list.scatter_from(&[5]), 1);
+        assert_eq!(list, [[5, 2], [3, 4]]);
+    }
+}
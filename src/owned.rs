@@ -0,0 +1,257 @@
+//! Buffers whose ownership can be lent out and later reclaimed
+
+use core::mem::{self, MaybeUninit};
+use core::ops::DerefMut;
+use core::ptr;
+
+use stable_deref_trait::StableDeref;
+
+use {AsMutSlice, AsSlice};
+
+/// A buffer that can be converted into an `OwnedBuffer`
+///
+/// This trait is implemented for all the `StableDeref + DerefMut` types whose `Target` is
+/// `AsMutSlice`
+pub trait IntoSlice: StableDeref + DerefMut + Sized
+where
+    Self::Target: AsMutSlice,
+{
+    /// Takes ownership of `self` and exposes its memory as a raw pointer / length pair
+    fn into_owned_buffer(self) -> OwnedBuffer<Self> {
+        OwnedBuffer::new(self)
+    }
+}
+
+impl<B> IntoSlice for B
+where
+    B: StableDeref + DerefMut,
+    B::Target: AsMutSlice,
+{
+}
+
+/// A buffer whose memory has been handed out as a raw pointer / length pair
+///
+/// This is meant to be used to move a buffer into a driver for the duration of a transfer (e.g.
+/// a DMA transfer) and then recover the original buffer, with its concrete type, once the transfer
+/// is over. The exposed memory is the slice view of the `Deref` target of `B` so, as `B`
+/// implements `StableDeref`, the pointer returned by `as_mut_ptr` remains valid even if the
+/// `OwnedBuffer` is moved, up until `into_inner` is called or the `OwnedBuffer` is dropped.
+pub struct OwnedBuffer<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    // NOTE `B` is stored in a `MaybeUninit` (a union) so that moving the `OwnedBuffer` around
+    // doesn't retag the `&mut` / `Box` in `B` under the Stacked Borrows model, which would
+    // invalidate `ptr`. The buffer is always initialized
+    buffer: MaybeUninit<B>,
+    ptr: *mut <B::Target as AsSlice>::Element,
+    len: usize,
+}
+
+impl<B> OwnedBuffer<B>
+where
+    B: StableDeref + DerefMut,
+    B::Target: AsMutSlice,
+{
+    /// Takes ownership of `buffer` and exposes its memory as a raw pointer / length pair
+    pub fn new(buffer: B) -> Self {
+        let mut buffer = MaybeUninit::new(buffer);
+        // NOTE(unsafe) `buffer` was just initialized; `ptr` is derived from it *after* it has been
+        // moved into its final (union) storage
+        let slice = unsafe { (**buffer.as_mut_ptr()).as_mut_slice() };
+        let ptr = slice.as_mut_ptr();
+        let len = slice.len();
+
+        OwnedBuffer { buffer, ptr, len }
+    }
+}
+
+impl<B> OwnedBuffer<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    /// Returns a raw pointer to the start of the buffer
+    pub fn as_ptr(&self) -> *const <B::Target as AsSlice>::Element {
+        self.ptr
+    }
+
+    /// Returns a raw mutable pointer to the start of the buffer
+    pub fn as_mut_ptr(&self) -> *mut <B::Target as AsSlice>::Element {
+        self.ptr
+    }
+
+    /// Returns the length of the buffer, in elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer has a length of 0
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reclaims the original buffer
+    ///
+    /// The caller must make sure that nothing (e.g. a DMA transfer) is still accessing the memory
+    /// exposed by `as_mut_ptr` before using the returned buffer
+    pub fn into_inner(self) -> B {
+        // NOTE(unsafe) `buffer` is initialized and `self` is forgotten so it won't be dropped twice
+        let buffer = unsafe { ptr::read(self.buffer.as_ptr()) };
+        mem::forget(self);
+        buffer
+    }
+}
+
+impl<B> Drop for OwnedBuffer<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    fn drop(&mut self) {
+        // NOTE(unsafe) `buffer` is initialized and this is the only place where it's dropped
+        unsafe { ptr::drop_in_place(self.buffer.as_mut_ptr()) }
+    }
+}
+
+// NOTE(unsafe) the raw pointer points into `buffer`, which we own
+unsafe impl<B> Send for OwnedBuffer<B>
+where
+    B: DerefMut + Send,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Send,
+{
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use core::ops::{Deref, DerefMut};
+    use std::boxed::Box;
+    use std::slice;
+
+    use stable_deref_trait::StableDeref;
+
+    use super::OwnedBuffer;
+    use {AsMutSlice, AsSlice};
+
+    fn moved<T>(x: T) -> T {
+        x
+    }
+
+    fn touch<B>(buffer: &OwnedBuffer<B>)
+    where
+        B: DerefMut,
+        B::Target: AsMutSlice<Element = u8>,
+    {
+        // NOTE(unsafe) the memory is owned by `buffer` and not otherwise borrowed
+        let memory = unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr(), buffer.len()) };
+        for (i, byte) in memory.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+    }
+
+    #[test]
+    fn static_mut_array() {
+        let buffer: &'static mut [u8; 8] = Box::leak(Box::new([0; 8]));
+
+        let owned = OwnedBuffer::new(buffer);
+        assert_eq!(owned.len(), 8);
+        assert!(!owned.is_empty());
+
+        // moving the `OwnedBuffer` must not invalidate its pointer
+        let owned = moved(owned);
+        touch(&owned);
+
+        let buffer = owned.into_inner();
+        assert_eq!(buffer, &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // NOTE(unsafe) `buffer` came from `Box::leak`
+        drop(unsafe { Box::from_raw(buffer) });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_slice() {
+        let buffer: Box<[u8]> = Box::new([0; 4]);
+
+        let owned = moved(OwnedBuffer::new(buffer));
+        touch(&owned);
+
+        assert_eq!(&*owned.into_inner(), &[0, 1, 2, 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn drop_releases_buffer() {
+        let owned = OwnedBuffer::new(Box::new([0u8; 4]));
+        touch(&owned);
+    }
+
+    #[test]
+    fn empty() {
+        let buffer: &'static mut [u8; 0] = Box::leak(Box::new([]));
+
+        let owned = OwnedBuffer::new(buffer);
+        assert!(owned.is_empty());
+
+        assert!(owned.into_inner().is_empty());
+    }
+
+    // `StableDeref` to its boxed target but `AsMutSlice` to inline storage that moves with it
+    pub struct Inline {
+        pub boxed: Box<[u8; 4]>,
+        pub inline: [u8; 4],
+    }
+
+    impl Inline {
+        pub fn new() -> Self {
+            Inline {
+                boxed: Box::new([0; 4]),
+                inline: [0; 4],
+            }
+        }
+    }
+
+    impl Deref for Inline {
+        type Target = [u8; 4];
+
+        fn deref(&self) -> &[u8; 4] {
+            &self.boxed
+        }
+    }
+
+    impl DerefMut for Inline {
+        fn deref_mut(&mut self) -> &mut [u8; 4] {
+            &mut self.boxed
+        }
+    }
+
+    // NOTE(unsafe) the target is boxed
+    unsafe impl StableDeref for Inline {}
+
+    impl AsSlice for Inline {
+        type Element = u8;
+
+        fn as_slice(&self) -> &[u8] {
+            &self.inline
+        }
+    }
+
+    impl AsMutSlice for Inline {
+        fn as_mut_slice(&mut self) -> &mut [u8] {
+            &mut self.inline
+        }
+    }
+
+    // the exposed memory must be the `Deref` target, not the (moving) `as_mut_slice` view
+    #[test]
+    fn inline_storage() {
+        let owned = moved(OwnedBuffer::new(Inline::new()));
+        touch(&owned);
+
+        let buffer = owned.into_inner();
+        assert_eq!(*buffer.boxed, [0, 1, 2, 3]);
+        assert_eq!(buffer.inline, [0; 4]);
+    }
+}
@@ -0,0 +1,172 @@
+//! Sub-range views of `As{,Mut}Slice` buffers
+
+use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
+
+use stable_deref_trait::StableDeref;
+
+use {AsMutSlice, AsSlice};
+
+/// A view into a sub-range of an owned `AsSlice` buffer
+///
+/// Unlike a sub-slice, a `SliceRange` keeps ownership of the whole buffer so, for example, a
+/// `SliceRange<&'static mut [u8; 128]>` can be handed to a DMA transfer that only uses part of the
+/// buffer. The whole buffer can be recovered with `into_inner`.
+///
+/// If `B` is `StableDeref` so is the view, which derefs to the range of `B`'s target: it satisfies
+/// the same `StableDeref + AsMutSlice + 'static` bounds as the whole buffer.
+///
+/// # Example
+///
+/// ```
+/// use as_slice::{AsSlice, SliceRange};
+///
+/// let range = SliceRange::new([0, 1, 2, 3, 4, 5, 6, 7], 2..6);
+///
+/// assert_eq!(range.as_slice(), &[2, 3, 4, 5]);
+/// assert_eq!(range.into_inner().len(), 8);
+/// ```
+pub struct SliceRange<B>
+where
+    B: AsSlice,
+{
+    buffer: B,
+    start: usize,
+    end: usize,
+}
+
+impl<B> SliceRange<B>
+where
+    B: AsSlice,
+{
+    /// Creates a view into the `range` elements of `buffer`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end or if the end of `range` is
+    /// greater than the length of `buffer`
+    pub fn new<R>(buffer: B, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = buffer.as_slice().len();
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(start <= end && end <= len);
+
+        SliceRange { buffer, start, end }
+    }
+
+    /// Returns the range of the buffer this view covers
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Releases the view and returns the whole buffer
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B> AsSlice for SliceRange<B>
+where
+    B: AsSlice,
+{
+    type Element = B::Element;
+
+    fn as_slice(&self) -> &[B::Element] {
+        &self.buffer.as_slice()[self.start..self.end]
+    }
+}
+
+impl<B> AsMutSlice for SliceRange<B>
+where
+    B: AsMutSlice,
+{
+    fn as_mut_slice(&mut self) -> &mut [B::Element] {
+        &mut self.buffer.as_mut_slice()[self.start..self.end]
+    }
+}
+
+impl<B> Deref for SliceRange<B>
+where
+    B: AsSlice + Deref,
+    B::Target: AsSlice,
+{
+    type Target = [<B::Target as AsSlice>::Element];
+
+    fn deref(&self) -> &Self::Target {
+        &(*self.buffer).as_slice()[self.start..self.end]
+    }
+}
+
+impl<B> DerefMut for SliceRange<B>
+where
+    B: AsSlice + DerefMut,
+    B::Target: AsMutSlice,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut (*self.buffer).as_mut_slice()[self.start..self.end]
+    }
+}
+
+// NOTE(unsafe) the view derefs to a fixed range of `B`'s target, which doesn't move when `B` (and
+// so `self`) is moved
+unsafe impl<B> StableDeref for SliceRange<B>
+where
+    B: AsSlice + StableDeref,
+    B::Target: AsSlice,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::boxed::Box;
+
+    use dma::WriteBuffer;
+    use owned::tests::Inline;
+    use stable_deref_trait::StableDeref;
+    use AsMutSlice;
+
+    use super::SliceRange;
+
+    fn dma<B>(buffer: &mut B) -> &mut [u8]
+    where
+        B: StableDeref + AsMutSlice<Element = u8> + WriteBuffer<Word = u8> + 'static,
+    {
+        buffer.as_mut_slice()
+    }
+
+    #[test]
+    fn stable_deref() {
+        let buffer: &'static mut [u8; 8] = Box::leak(Box::new([0; 8]));
+
+        let mut range = SliceRange::new(buffer, 2..6);
+        dma(&mut range).copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(*range, [1, 2, 3, 4]);
+
+        let buffer = range.into_inner();
+        assert_eq!(buffer, &[0, 0, 1, 2, 3, 4, 0, 0]);
+
+        // NOTE(unsafe) `buffer` came from `Box::leak`
+        drop(unsafe { Box::from_raw(buffer) });
+    }
+
+    #[test]
+    fn inline_storage() {
+        let mut range = SliceRange::new(Inline::new(), 1..3);
+        range[0] = 1;
+
+        let buffer = range.into_inner();
+        assert_eq!(*buffer.boxed, [0, 1, 0, 0]);
+        assert_eq!(buffer.inline, [0; 4]);
+    }
+}
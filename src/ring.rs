@@ -0,0 +1,299 @@
+//! Ring buffer over `AsMutSlice` storage
+
+use core::cmp;
+
+use {AsMutSlice, AsSlice};
+
+/// A fixed capacity ring (circular) buffer that uses any `AsMutSlice` type as storage
+///
+/// The capacity of the ring is the length of the slice view of the storage; the initial contents
+/// of the storage are ignored (and overwritten as elements are pushed)
+///
+/// Elements are copied out of the storage, which keeps owning them, so `pop` and `read_slice` --
+/// the whole read side of the ring -- as well as `write_slice` need `Element: Copy`. Only `push`,
+/// `as_slices` and `as_mut_slices` work with any element type.
+///
+/// # Example
+///
+/// ```
+/// use as_slice::Ring;
+///
+/// let mut ring = Ring::new([0u8; 4]);
+///
+/// assert_eq!(ring.write_slice(&[1, 2, 3]), 3);
+/// assert_eq!(ring.pop(), Some(1));
+/// assert_eq!(ring.write_slice(&[4, 5, 6]), 2);
+///
+/// // the contents wrap around the end of the storage
+/// assert_eq!(ring.as_slices(), (&[2, 3, 4][..], &[5][..]));
+///
+/// let mut out = [0; 4];
+/// assert_eq!(ring.read_slice(&mut out), 4);
+/// assert_eq!(out, [2, 3, 4, 5]);
+/// ```
+pub struct Ring<B>
+where
+    B: AsMutSlice,
+{
+    buffer: B,
+    // index of the oldest element
+    head: usize,
+    len: usize,
+}
+
+impl<B> Ring<B>
+where
+    B: AsMutSlice,
+{
+    /// Creates an empty ring that uses `buffer` as storage
+    pub fn new(buffer: B) -> Self {
+        Ring {
+            buffer,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements the ring can hold
+    pub fn capacity(&self) -> usize {
+        self.buffer.as_slice().len()
+    }
+
+    /// Returns the number of elements in the ring
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the ring contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the ring is at full capacity
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Removes all the elements from the ring
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Appends an `item` to the back of the ring
+    ///
+    /// Returns back the `item` if the ring is full
+    pub fn push(&mut self, item: B::Element) -> Result<(), B::Element> {
+        let cap = self.capacity();
+
+        if self.len == cap {
+            return Err(item);
+        }
+
+        let tail = (self.head + self.len) % cap;
+        self.buffer.as_mut_slice()[tail] = item;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the item at the front of the ring and returns it, or `None` if the ring is empty
+    pub fn pop(&mut self) -> Option<B::Element>
+    where
+        B::Element: Copy,
+    {
+        if self.len == 0 {
+            return None;
+        }
+
+        let item = self.buffer.as_slice()[self.head];
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+
+        Some(item)
+    }
+
+    /// Appends as many elements of `data` as fit in the ring and returns how many were appended
+    pub fn write_slice<S>(&mut self, data: &S) -> usize
+    where
+        S: ?Sized + AsSlice<Element = B::Element>,
+        B::Element: Copy,
+    {
+        let data = data.as_slice();
+        let cap = self.capacity();
+        let n = cmp::min(cap - self.len, data.len());
+
+        if n == 0 {
+            return 0;
+        }
+
+        let tail = (self.head + self.len) % cap;
+        let first = cmp::min(n, cap - tail);
+        let buffer = self.buffer.as_mut_slice();
+        buffer[tail..tail + first].copy_from_slice(&data[..first]);
+        buffer[..n - first].copy_from_slice(&data[first..n]);
+        self.len += n;
+
+        n
+    }
+
+    /// Removes elements from the front of the ring into `out` and returns how many were removed
+    pub fn read_slice<S>(&mut self, out: &mut S) -> usize
+    where
+        S: ?Sized + AsMutSlice<Element = B::Element>,
+        B::Element: Copy,
+    {
+        let out = out.as_mut_slice();
+        let n = cmp::min(self.len, out.len());
+
+        if n == 0 {
+            return 0;
+        }
+
+        let cap = self.capacity();
+        let first = cmp::min(n, cap - self.head);
+        let buffer = self.buffer.as_slice();
+        out[..first].copy_from_slice(&buffer[self.head..self.head + first]);
+        out[first..n].copy_from_slice(&buffer[..n - first]);
+        self.head = (self.head + n) % cap;
+        self.len -= n;
+
+        n
+    }
+
+    /// Returns the contents of the ring, from front to back, as two contiguous slices
+    ///
+    /// The second slice is empty unless the contents wrap around the end of the storage
+    pub fn as_slices(&self) -> (&[B::Element], &[B::Element]) {
+        let buffer = self.buffer.as_slice();
+        let end = self.head + self.len;
+
+        if end <= buffer.len() {
+            (&buffer[self.head..end], &[])
+        } else {
+            (&buffer[self.head..], &buffer[..end - buffer.len()])
+        }
+    }
+
+    /// Returns the contents of the ring, from front to back, as two contiguous mutable slices
+    ///
+    /// The second slice is empty unless the contents wrap around the end of the storage
+    pub fn as_mut_slices(&mut self) -> (&mut [B::Element], &mut [B::Element]) {
+        let buffer = self.buffer.as_mut_slice();
+        let cap = buffer.len();
+        let end = self.head + self.len;
+
+        if end <= cap {
+            (&mut buffer[self.head..end], &mut [])
+        } else {
+            let (front, back) = buffer.split_at_mut(self.head);
+            (back, &mut front[..end - cap])
+        }
+    }
+
+    /// Releases the storage
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ring;
+
+    // a ring whose contents start at index 3 of its storage
+    fn shifted() -> Ring<[u8; 4]> {
+        let mut ring = Ring::new([0; 4]);
+        assert_eq!(ring.write_slice(&[0, 0, 0]), 3);
+        assert_eq!(ring.read_slice(&mut [0; 3]), 3);
+        ring
+    }
+
+    #[test]
+    fn write_slice_wrapped() {
+        let mut ring = shifted();
+
+        assert_eq!(ring.write_slice(&[1, 2, 3]), 3);
+        assert_eq!(ring.as_slices(), (&[1][..], &[2, 3][..]));
+        assert_eq!(ring.into_inner(), [2, 3, 0, 1]);
+    }
+
+    #[test]
+    fn write_slice_full() {
+        let mut ring = shifted();
+
+        assert_eq!(ring.write_slice(&[1, 2, 3, 4, 5]), 4);
+        assert!(ring.is_full());
+        assert_eq!(ring.write_slice(&[6]), 0);
+        assert_eq!(ring.push(6), Err(6));
+        assert_eq!(ring.as_slices(), (&[1][..], &[2, 3, 4][..]));
+    }
+
+    #[test]
+    fn read_slice_wrapped() {
+        let mut ring = shifted();
+        ring.write_slice(&[1, 2, 3]);
+
+        let mut out = [0; 2];
+        assert_eq!(ring.read_slice(&mut out), 2);
+        assert_eq!(out, [1, 2]);
+        assert_eq!(ring.as_slices(), (&[3][..], &[][..]));
+    }
+
+    #[test]
+    fn read_slice_full() {
+        let mut ring = shifted();
+        ring.write_slice(&[1, 2, 3, 4]);
+
+        let mut out = [0; 5];
+        assert_eq!(ring.read_slice(&mut out), 4);
+        assert_eq!(out, [1, 2, 3, 4, 0]);
+        assert!(ring.is_empty());
+        assert_eq!(ring.read_slice(&mut out), 0);
+        assert_eq!(ring.pop(), None);
+    }
+
+    #[test]
+    fn as_mut_slices_wrapped() {
+        let mut ring = shifted();
+        ring.write_slice(&[1, 2, 3]);
+
+        {
+            let (front, back) = ring.as_mut_slices();
+            assert_eq!(front, &mut [1]);
+            assert_eq!(back, &mut [2, 3]);
+
+            front[0] = 4;
+            back[1] = 5;
+        }
+
+        let mut out = [0; 3];
+        ring.read_slice(&mut out);
+        assert_eq!(out, [4, 2, 5]);
+    }
+
+    #[test]
+    fn as_mut_slices_full() {
+        let mut ring = shifted();
+        ring.write_slice(&[1, 2, 3, 4]);
+
+        let (front, back) = ring.as_mut_slices();
+        assert_eq!(front, &mut [1]);
+        assert_eq!(back, &mut [2, 3, 4]);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut ring = Ring::new([0u8; 0]);
+
+        assert_eq!(ring.capacity(), 0);
+        assert!(ring.is_empty());
+        assert!(ring.is_full());
+        assert_eq!(ring.push(1), Err(1));
+        assert_eq!(ring.pop(), None);
+        assert_eq!(ring.write_slice(&[1, 2]), 0);
+        assert_eq!(ring.read_slice(&mut [0; 2]), 0);
+        assert_eq!(ring.as_slices(), (&[][..], &[][..]));
+        assert_eq!(ring.as_mut_slices(), (&mut [][..], &mut [][..]));
+    }
+}
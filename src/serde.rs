@@ -0,0 +1,864 @@
+//! `serde` helpers for `As{,Mut}Slice` buffers
+//!
+//! The functions in this module encode any `AsSlice` buffer as a sequence of its elements, so the
+//! encoding doesn't depend on the concrete buffer type. The [`bytes`] submodule contains the
+//! equivalent functions for `AsSlice<Element = u8>` buffers; these use the more compact byte
+//! string encoding when the format supports it.
+//!
+//! Any `AsMutSlice` buffer can be deserialized into with `deserialize_into_mut_slice`. For use with
+//! `#[serde(with = "..")]` there are three modules, which only differ in how they deserialize:
+//!
+//! - `as_slice::serde` deserializes into the slice view of a `Default` buffer, which must have the
+//!   length of the encoded data
+//! - [`array`](array/index.html) deserializes into an array of any length; it doesn't require `Default`
+//! - [`growable`] deserializes into an empty (`Default`) buffer that grows one element at a time,
+//!   e.g. a `heapless::Vec`, and fails if the buffer runs out of capacity (see `TryPush`)
+//!
+//! The `bytes` submodule has the same three variants.
+//!
+//! ```
+//! extern crate as_slice;
+//! extern crate serde;
+//! #[macro_use]
+//! extern crate serde_derive;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Config {
+//!     #[serde(with = "as_slice::serde::array")]
+//!     table: [u16; 64],
+//!     #[serde(with = "as_slice::serde::bytes")]
+//!     key: [u8; 16],
+//! }
+//! # fn main() {}
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+use serde_crate::de::{self, IgnoredAny, SeqAccess, Visitor};
+use serde_crate::ser::SerializeSeq;
+use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use {AsMutSlice, AsSlice};
+
+/// Serializes the slice view of `buffer` as a sequence
+pub fn serialize_as_slice<B, S>(buffer: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B: ?Sized + AsSlice,
+    B::Element: Serialize,
+    S: Serializer,
+{
+    let slice = buffer.as_slice();
+    let mut seq = serializer.serialize_seq(Some(slice.len()))?;
+    for element in slice {
+        seq.serialize_element(element)?;
+    }
+    seq.end()
+}
+
+/// Deserializes a sequence into the slice view of `buffer`
+///
+/// Returns an error if the length of the sequence doesn't match the length of the slice view
+pub fn deserialize_into_mut_slice<'de, B, D>(
+    deserializer: D,
+    buffer: &mut B,
+) -> Result<(), D::Error>
+where
+    B: ?Sized + AsMutSlice,
+    B::Element: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(SliceVisitor {
+        slice: buffer.as_mut_slice(),
+        _de: PhantomData,
+    })
+}
+
+/// Serializes the slice view of `buffer` as a sequence
+///
+/// Alias of `serialize_as_slice` for use with `#[serde(with = "as_slice::serde")]`
+pub fn serialize<B, S>(buffer: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+    B: ?Sized + AsSlice,
+    B::Element: Serialize,
+    S: Serializer,
+{
+    serialize_as_slice(buffer, serializer)
+}
+
+/// Deserializes a sequence into the slice view of a `Default` buffer
+///
+/// Returns an error if the length of the sequence doesn't match the length of the slice view
+pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+where
+    B: AsMutSlice + Default,
+    B::Element: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let mut buffer = B::default();
+    deserialize_into_mut_slice(deserializer, &mut buffer)?;
+    Ok(buffer)
+}
+
+/// A buffer that can grow one element at a time, up to its capacity
+///
+/// This trait is implemented for `Vec<T>` (`alloc`), `heapless::Vec<T, N>`,
+/// `arrayvec::ArrayVec<T, CAP>` and `smallvec::SmallVec<A>`
+pub trait TryPush: AsSlice {
+    /// Appends `element` to the buffer
+    ///
+    /// Returns `element` back if the buffer is full
+    fn try_push(&mut self, element: Self::Element) -> Result<(), Self::Element>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryPush for Vec<T> {
+    fn try_push(&mut self, element: T) -> Result<(), T> {
+        self.push(element);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> TryPush for heapless::Vec<T, N> {
+    fn try_push(&mut self, element: T) -> Result<(), T> {
+        self.push(element)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> TryPush for arrayvec::ArrayVec<T, CAP> {
+    fn try_push(&mut self, element: T) -> Result<(), T> {
+        arrayvec::ArrayVec::try_push(self, element).map_err(|e| e.element())
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A> TryPush for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+{
+    fn try_push(&mut self, element: A::Item) -> Result<(), A::Item> {
+        self.push(element);
+        Ok(())
+    }
+}
+
+/// `serde` helpers for arrays of any length
+pub mod array {
+    use core::marker::PhantomData;
+
+    use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `array` as a sequence
+    ///
+    /// Alias of `serialize_as_slice` for use with `#[serde(with = "as_slice::serde::array")]`
+    pub fn serialize<T, S, const N: usize>(array: &[T; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        super::serialize_as_slice(array, serializer)
+    }
+
+    /// Deserializes a sequence into an array
+    ///
+    /// Returns an error if the length of the sequence is not `N`
+    pub fn deserialize<'de, T, D, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(super::ArrayVisitor {
+            _array: PhantomData,
+        })
+    }
+}
+
+/// `serde` helpers for `TryPush` buffers
+pub mod growable {
+    use core::marker::PhantomData;
+
+    use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::TryPush;
+
+    /// Serializes the slice view of `buffer` as a sequence
+    ///
+    /// Alias of `serialize_as_slice` for use with `#[serde(with = "as_slice::serde::growable")]`
+    pub fn serialize<B, S>(buffer: &B, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        B: ?Sized + TryPush,
+        B::Element: Serialize,
+        S: Serializer,
+    {
+        super::serialize_as_slice(buffer, serializer)
+    }
+
+    /// Deserializes a sequence by pushing its elements into an empty (`Default`) buffer
+    ///
+    /// Returns an error if the buffer runs out of capacity
+    pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+    where
+        B: TryPush + Default,
+        B::Element: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(super::GrowableVisitor {
+            _buffer: PhantomData,
+        })
+    }
+}
+
+struct SliceVisitor<'a, 'de, T> {
+    slice: &'a mut [T],
+    _de: PhantomData<&'de ()>,
+}
+
+impl<'a, 'de, T> Visitor<'de> for SliceVisitor<'a, 'de, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} elements", self.slice.len())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        fill(self.slice, &mut seq)
+    }
+}
+
+struct ArrayVisitor<T, const N: usize> {
+    _array: PhantomData<[T; N]>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<[T; N], A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array = PartialArray::new();
+
+        while array.len < N {
+            match seq.next_element()? {
+                Some(element) => array.push(element),
+                None => return Err(de::Error::invalid_length(array.len, &self)),
+            }
+        }
+
+        let rest = count_rest(&mut seq)?;
+        if rest != 0 {
+            return Err(de::Error::invalid_length(N + rest, &self));
+        }
+
+        Ok(array.into_array())
+    }
+}
+
+struct GrowableVisitor<B> {
+    _buffer: PhantomData<B>,
+}
+
+impl<'de, B> Visitor<'de> for GrowableVisitor<B>
+where
+    B: TryPush + Default,
+    B::Element: Deserialize<'de>,
+{
+    type Value = B;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(FITS)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<B, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut buffer = B::default();
+        extend(&mut buffer, &mut seq)?;
+        Ok(buffer)
+    }
+}
+
+/// An array whose first `len` elements are initialized
+struct PartialArray<T, const N: usize> {
+    array: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    fn new() -> Self {
+        PartialArray {
+            // NOTE(unsafe) an array of `MaybeUninit`s doesn't need to be initialized
+            array: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the array is full
+    fn push(&mut self, element: T) {
+        self.array[self.len] = MaybeUninit::new(element);
+        self.len += 1;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the array is not full
+    fn into_array(self) -> [T; N] {
+        assert_eq!(self.len, N);
+
+        // NOTE(unsafe) all the elements are initialized, `[MaybeUninit<T>; N]` has the same layout
+        // as `[T; N]` and `self` is forgotten so the elements won't be dropped
+        let array = unsafe { ptr::read(self.array.as_ptr() as *const [T; N]) };
+        mem::forget(self);
+        array
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        for element in &mut self.array[..self.len] {
+            // NOTE(unsafe) the first `len` elements are initialized
+            unsafe { ptr::drop_in_place(element.as_mut_ptr()) }
+        }
+    }
+}
+
+const FITS: &str = "a sequence that fits in the buffer";
+
+fn fill<'de, T, A>(slice: &mut [T], seq: &mut A) -> Result<(), A::Error>
+where
+    T: Deserialize<'de>,
+    A: SeqAccess<'de>,
+{
+    let len = slice.len();
+    let expected = &ExpectedLen(len);
+
+    for (i, slot) in slice.iter_mut().enumerate() {
+        *slot = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(i, expected))?;
+    }
+
+    let rest = count_rest(seq)?;
+    if rest != 0 {
+        return Err(de::Error::invalid_length(len + rest, expected));
+    }
+
+    Ok(())
+}
+
+fn extend<'de, B, A>(buffer: &mut B, seq: &mut A) -> Result<(), A::Error>
+where
+    B: ?Sized + TryPush,
+    B::Element: Deserialize<'de>,
+    A: SeqAccess<'de>,
+{
+    let mut len = 0;
+
+    while let Some(element) = seq.next_element()? {
+        len += 1;
+
+        if buffer.try_push(element).is_err() {
+            let len = len + count_rest(seq)?;
+            return Err(de::Error::invalid_length(len, &FITS));
+        }
+    }
+
+    Ok(())
+}
+
+/// Consumes the rest of the sequence and returns the number of elements it had
+fn count_rest<'de, A>(seq: &mut A) -> Result<usize, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let mut n = 0;
+    while seq.next_element::<IgnoredAny>()?.is_some() {
+        n += 1;
+    }
+    Ok(n)
+}
+
+struct ExpectedLen(usize);
+
+impl de::Expected for ExpectedLen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} elements", self.0)
+    }
+}
+
+/// `serde` helpers for `As{,Mut}Slice<Element = u8>` buffers
+pub mod bytes {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde_crate::de::{self, SeqAccess, Visitor};
+    use serde_crate::{Deserializer, Serializer};
+
+    use super::TryPush;
+    use {AsMutSlice, AsSlice};
+
+    /// Serializes the slice view of `buffer` as a byte string
+    pub fn serialize_as_slice<B, S>(buffer: &B, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        B: ?Sized + AsSlice<Element = u8>,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(buffer.as_slice())
+    }
+
+    /// Deserializes a byte string (or a sequence of bytes) into the slice view of `buffer`
+    ///
+    /// Returns an error if the length of the byte string doesn't match the length of the slice
+    /// view
+    pub fn deserialize_into_mut_slice<'de, B, D>(
+        deserializer: D,
+        buffer: &mut B,
+    ) -> Result<(), D::Error>
+    where
+        B: ?Sized + AsMutSlice<Element = u8>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BytesVisitor {
+            slice: buffer.as_mut_slice(),
+        })
+    }
+
+    /// Serializes the slice view of `buffer` as a byte string
+    ///
+    /// Alias of `serialize_as_slice` for use with `#[serde(with = "as_slice::serde::bytes")]`
+    pub fn serialize<B, S>(buffer: &B, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        B: ?Sized + AsSlice<Element = u8>,
+        S: Serializer,
+    {
+        serialize_as_slice(buffer, serializer)
+    }
+
+    /// Deserializes a byte string (or a sequence of bytes) into the slice view of a `Default`
+    /// buffer
+    ///
+    /// Returns an error if the length of the byte string doesn't match the length of the slice
+    /// view
+    pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+    where
+        B: AsMutSlice<Element = u8> + Default,
+        D: Deserializer<'de>,
+    {
+        let mut buffer = B::default();
+        deserialize_into_mut_slice(deserializer, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// `serde` helpers for byte arrays of any length
+    pub mod array {
+        use serde_crate::{Deserializer, Serializer};
+
+        /// Serializes `array` as a byte string
+        ///
+        /// Alias of `serialize_as_slice` for use with
+        /// `#[serde(with = "as_slice::serde::bytes::array")]`
+        pub fn serialize<S, const N: usize>(
+            array: &[u8; N],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            super::serialize_as_slice(array, serializer)
+        }
+
+        /// Deserializes a byte string (or a sequence of bytes) into a byte array
+        ///
+        /// Returns an error if the length of the byte string is not `N`
+        pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let mut array = [0; N];
+            super::deserialize_into_mut_slice(deserializer, &mut array)?;
+            Ok(array)
+        }
+    }
+
+    /// `serde` helpers for `TryPush<Element = u8>` buffers
+    pub mod growable {
+        use core::marker::PhantomData;
+
+        use serde_crate::{Deserializer, Serializer};
+
+        use super::super::TryPush;
+
+        /// Serializes the slice view of `buffer` as a byte string
+        ///
+        /// Alias of `serialize_as_slice` for use with
+        /// `#[serde(with = "as_slice::serde::bytes::growable")]`
+        pub fn serialize<B, S>(buffer: &B, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            B: ?Sized + TryPush<Element = u8>,
+            S: Serializer,
+        {
+            super::serialize_as_slice(buffer, serializer)
+        }
+
+        /// Deserializes a byte string (or a sequence of bytes) by pushing its bytes into an empty
+        /// (`Default`) buffer
+        ///
+        /// Returns an error if the buffer runs out of capacity
+        pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+        where
+            B: TryPush<Element = u8> + Default,
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_bytes(super::GrowableBytesVisitor {
+                _buffer: PhantomData,
+            })
+        }
+    }
+
+    struct BytesVisitor<'a> {
+        slice: &'a mut [u8],
+    }
+
+    impl<'a, 'de> Visitor<'de> for BytesVisitor<'a> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a byte string of length {}", self.slice.len())
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<(), E>
+        where
+            E: de::Error,
+        {
+            if v.len() != self.slice.len() {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+
+            self.slice.copy_from_slice(v);
+
+            Ok(())
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            super::fill(self.slice, &mut seq)
+        }
+    }
+
+    struct GrowableBytesVisitor<B> {
+        _buffer: PhantomData<B>,
+    }
+
+    impl<'de, B> Visitor<'de> for GrowableBytesVisitor<B>
+    where
+        B: TryPush<Element = u8> + Default,
+    {
+        type Value = B;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a byte string that fits in the buffer")
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<B, E>
+        where
+            E: de::Error,
+        {
+            let mut buffer = B::default();
+
+            for byte in v {
+                if buffer.try_push(*byte).is_err() {
+                    return Err(E::invalid_length(v.len(), &self));
+                }
+            }
+
+            Ok(buffer)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<B, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut buffer = B::default();
+            super::extend(&mut buffer, &mut seq)?;
+            Ok(buffer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_derive;
+    extern crate serde_json;
+    extern crate serde_test;
+
+    use std::string::{String, ToString};
+
+    use self::serde_derive::{Deserialize, Serialize};
+    use self::serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Table {
+        #[serde(with = "::serde")]
+        table: [u16; 2],
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Array {
+        #[serde(with = "::serde::array")]
+        array: [u16; 40],
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Strings {
+        #[serde(with = "::serde::array")]
+        array: [String; 3],
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Key {
+        #[serde(with = "::serde::bytes")]
+        key: [u8; 4],
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct LongKey {
+        #[serde(with = "::serde::bytes::array")]
+        key: [u8; 40],
+    }
+
+    fn error<T>(json: &str) -> String
+    where
+        T: for<'de> serde_crate::Deserialize<'de>,
+    {
+        serde_json::from_str::<T>(json).err().unwrap().to_string()
+    }
+
+    #[test]
+    fn default_buffer() {
+        let table = Table { table: [1, 2] };
+
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(json, r#"{"table":[1,2]}"#);
+        assert_eq!(serde_json::from_str::<Table>(&json).unwrap(), table);
+
+        assert!(error::<Table>(r#"{"table":[1]}"#)
+            .starts_with("invalid length 1, expected a sequence of 2 elements"));
+        assert!(error::<Table>(r#"{"table":[1,2,3,4]}"#)
+            .starts_with("invalid length 4, expected a sequence of 2 elements"));
+    }
+
+    #[test]
+    fn array() {
+        let mut array = Array { array: [0; 40] };
+        for (i, x) in array.array.iter_mut().enumerate() {
+            *x = i as u16;
+        }
+
+        let json = serde_json::to_string(&array).unwrap();
+        assert_eq!(serde_json::from_str::<Array>(&json).unwrap(), array);
+
+        assert!(error::<Array>(r#"{"array":[1,2,3]}"#)
+            .starts_with("invalid length 3, expected a sequence of 40 elements"));
+    }
+
+    #[test]
+    fn array_tokens() {
+        assert_tokens(
+            &Strings {
+                array: ["a".to_string(), "b".to_string(), "c".to_string()],
+            },
+            &[
+                Token::Struct {
+                    name: "Strings",
+                    len: 1,
+                },
+                Token::Str("array"),
+                Token::Seq { len: Some(3) },
+                Token::Str("a"),
+                Token::Str("b"),
+                Token::Str("c"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    // the already deserialized elements must be dropped
+    #[test]
+    fn array_error_drops_elements() {
+        assert!(error::<Strings>(r#"{"array":["a","b"]}"#)
+            .starts_with("invalid length 2, expected a sequence of 3 elements"));
+        assert!(error::<Strings>(r#"{"array":["a","b","c","d"]}"#)
+            .starts_with("invalid length 4, expected a sequence of 3 elements"));
+        assert!(error::<Strings>(r#"{"array":["a",1,"c"]}"#).starts_with("invalid type"));
+    }
+
+    #[test]
+    fn bytes() {
+        let key = Key { key: [1, 2, 3, 4] };
+        let tokens = [
+            Token::Struct {
+                name: "Key",
+                len: 1,
+            },
+            Token::Str("key"),
+            Token::Bytes(&[1, 2, 3, 4]),
+            Token::StructEnd,
+        ];
+
+        assert_tokens(&key, &tokens);
+
+        // a sequence of bytes is also accepted
+        assert_eq!(
+            serde_json::from_str::<Key>(r#"{"key":[1,2,3,4]}"#).unwrap(),
+            key
+        );
+
+        assert_de_tokens_error::<Key>(
+            &[
+                Token::Struct {
+                    name: "Key",
+                    len: 1,
+                },
+                Token::Str("key"),
+                Token::Bytes(&[1, 2, 3]),
+            ],
+            "invalid length 3, expected a byte string of length 4",
+        );
+    }
+
+    #[test]
+    fn bytes_array() {
+        let key = LongKey { key: [7; 40] };
+
+        assert_tokens(
+            &key,
+            &[
+                Token::Struct {
+                    name: "LongKey",
+                    len: 1,
+                },
+                Token::Str("key"),
+                Token::Bytes(&[7; 40]),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    mod heapless {
+        use heapless::Vec;
+
+        use super::serde_derive::{Deserialize, Serialize};
+        use super::serde_json;
+        use super::serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Offsets {
+            #[serde(with = "::serde::growable")]
+            table: Vec<u16, 8>,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Small {
+            #[serde(with = "::serde::growable")]
+            table: Vec<u16, 2>,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Packet {
+            #[serde(with = "::serde::bytes::growable")]
+            payload: Vec<u8, 4>,
+        }
+
+        #[test]
+        fn growable() {
+            let offsets = Offsets {
+                table: Vec::from_slice(&[1, 2, 3]).unwrap(),
+            };
+
+            let json = serde_json::to_string(&offsets).unwrap();
+            assert_eq!(json, r#"{"table":[1,2,3]}"#);
+            assert_eq!(serde_json::from_str::<Offsets>(&json).unwrap(), offsets);
+
+            let empty = serde_json::from_str::<Offsets>(r#"{"table":[]}"#).unwrap();
+            assert!(empty.table.is_empty());
+        }
+
+        #[test]
+        fn growable_full() {
+            assert!(super::error::<Small>(r#"{"table":[1,2,3]}"#)
+                .starts_with("invalid length 3, expected a sequence that fits in the buffer"));
+        }
+
+        #[test]
+        fn bytes_growable() {
+            let packet = Packet {
+                payload: Vec::from_slice(&[1, 2]).unwrap(),
+            };
+            let tokens = [
+                Token::Struct {
+                    name: "Packet",
+                    len: 1,
+                },
+                Token::Str("payload"),
+                Token::Bytes(&[1, 2]),
+                Token::StructEnd,
+            ];
+
+            super::assert_tokens(&packet, &tokens);
+            assert_de_tokens(
+                &packet,
+                &[
+                    Token::Struct {
+                        name: "Packet",
+                        len: 1,
+                    },
+                    Token::Str("payload"),
+                    Token::Seq { len: Some(2) },
+                    Token::U8(1),
+                    Token::U8(2),
+                    Token::SeqEnd,
+                    Token::StructEnd,
+                ],
+            );
+
+            assert_de_tokens_error::<Packet>(
+                &[
+                    Token::Struct {
+                        name: "Packet",
+                        len: 1,
+                    },
+                    Token::Str("payload"),
+                    Token::Bytes(&[1, 2, 3, 4, 5]),
+                ],
+                "invalid length 5, expected a byte string that fits in the buffer",
+            );
+        }
+    }
+}
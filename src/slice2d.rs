@@ -0,0 +1,315 @@
+//! Two dimensional views of buffers
+
+use {AsFlatSlice, AsMutFlatSlice, AsMutSlice, AsSlice};
+
+/// Something that can be seen as an immutable two dimensional grid of elements, stored row by row
+///
+/// Row `i` starts at index `i * stride` of the flat slice view and spans `cols` elements
+pub trait AsSlice2D {
+    /// The element type of the grid
+    type Element;
+
+    /// Returns the number of rows
+    fn rows(&self) -> usize;
+
+    /// Returns the number of columns
+    fn cols(&self) -> usize;
+
+    /// Returns the distance, in elements, between the starts of two consecutive rows
+    fn stride(&self) -> usize;
+
+    /// Returns the immutable flat slice view of the grid storage
+    fn as_flat(&self) -> &[Self::Element];
+
+    /// Returns the `i`-th row
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= rows`
+    fn row(&self, i: usize) -> &[Self::Element] {
+        assert!(i < self.rows());
+
+        let start = i * self.stride();
+        &self.as_flat()[start..start + self.cols()]
+    }
+
+    /// Returns the element at row `r` and column `c`, or `None` if out of bounds
+    fn get(&self, r: usize, c: usize) -> Option<&Self::Element> {
+        if r < self.rows() && c < self.cols() {
+            self.as_flat().get(r * self.stride() + c)
+        } else {
+            None
+        }
+    }
+}
+
+/// Something that can be seen as a mutable two dimensional grid of elements, stored row by row
+pub trait AsMutSlice2D: AsSlice2D {
+    /// Returns the mutable flat slice view of the grid storage
+    fn as_mut_flat(&mut self) -> &mut [Self::Element];
+
+    /// Returns the `i`-th row
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= rows`
+    fn row_mut(&mut self, i: usize) -> &mut [Self::Element] {
+        assert!(i < self.rows());
+
+        let start = i * self.stride();
+        let end = start + self.cols();
+        &mut self.as_mut_flat()[start..end]
+    }
+
+    /// Returns the element at row `r` and column `c`, or `None` if out of bounds
+    fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut Self::Element> {
+        if r < self.rows() && c < self.cols() {
+            let i = r * self.stride() + c;
+            self.as_mut_flat().get_mut(i)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, S> AsSlice2D for &'a S
+where
+    S: ?Sized + AsSlice2D,
+{
+    type Element = S::Element;
+
+    fn rows(&self) -> usize {
+        (**self).rows()
+    }
+
+    fn cols(&self) -> usize {
+        (**self).cols()
+    }
+
+    fn stride(&self) -> usize {
+        (**self).stride()
+    }
+
+    fn as_flat(&self) -> &[S::Element] {
+        (**self).as_flat()
+    }
+}
+
+impl<'a, S> AsSlice2D for &'a mut S
+where
+    S: ?Sized + AsSlice2D,
+{
+    type Element = S::Element;
+
+    fn rows(&self) -> usize {
+        (**self).rows()
+    }
+
+    fn cols(&self) -> usize {
+        (**self).cols()
+    }
+
+    fn stride(&self) -> usize {
+        (**self).stride()
+    }
+
+    fn as_flat(&self) -> &[S::Element] {
+        (**self).as_flat()
+    }
+}
+
+impl<'a, S> AsMutSlice2D for &'a mut S
+where
+    S: ?Sized + AsMutSlice2D,
+{
+    fn as_mut_flat(&mut self) -> &mut [S::Element] {
+        (**self).as_mut_flat()
+    }
+}
+
+impl<T, const W: usize, const H: usize> AsSlice2D for [[T; W]; H] {
+    type Element = T;
+
+    fn rows(&self) -> usize {
+        H
+    }
+
+    fn cols(&self) -> usize {
+        W
+    }
+
+    fn stride(&self) -> usize {
+        W
+    }
+
+    fn as_flat(&self) -> &[T] {
+        self.as_flat_slice()
+    }
+}
+
+impl<T, const W: usize, const H: usize> AsMutSlice2D for [[T; W]; H] {
+    fn as_mut_flat(&mut self) -> &mut [T] {
+        self.as_mut_flat_slice()
+    }
+}
+
+/// A two dimensional view over flat `AsSlice` storage
+///
+/// # Example
+///
+/// ```
+/// use as_slice::{AsSlice2D, Strided};
+///
+/// // 2 rows of 3 pixels, each row padded to 4 pixels
+/// let image = Strided::with_stride([1, 2, 3, 0, 4, 5, 6, 0], 2, 3, 4);
+///
+/// assert_eq!(image.row(1), &[4, 5, 6]);
+/// assert_eq!(image.get(0, 2), Some(&3));
+/// assert_eq!(image.get(0, 3), None);
+/// ```
+pub struct Strided<B>
+where
+    B: AsSlice,
+{
+    buffer: B,
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+impl<B> Strided<B>
+where
+    B: AsSlice,
+{
+    /// Views `buffer` as `rows` contiguous rows of `cols` elements
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too small to hold the grid
+    pub fn new(buffer: B, rows: usize, cols: usize) -> Self {
+        Strided::with_stride(buffer, rows, cols, cols)
+    }
+
+    /// Views `buffer` as `rows` rows of `cols` elements whose starts are `stride` elements apart
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride < cols` or if `buffer` is too small to hold the grid
+    pub fn with_stride(buffer: B, rows: usize, cols: usize, stride: usize) -> Self {
+        assert!(stride >= cols);
+
+        if rows != 0 {
+            let len = (rows - 1)
+                .checked_mul(stride)
+                .and_then(|n| n.checked_add(cols))
+                .expect("grid size overflows `usize`");
+            assert!(len <= buffer.as_slice().len());
+        }
+
+        Strided {
+            buffer,
+            rows,
+            cols,
+            stride,
+        }
+    }
+
+    /// Releases the underlying storage
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B> AsSlice2D for Strided<B>
+where
+    B: AsSlice,
+{
+    type Element = B::Element;
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn stride(&self) -> usize {
+        self.stride
+    }
+
+    fn as_flat(&self) -> &[B::Element] {
+        self.buffer.as_slice()
+    }
+}
+
+impl<B> AsMutSlice2D for Strided<B>
+where
+    B: AsMutSlice,
+{
+    fn as_mut_flat(&mut self) -> &mut [B::Element] {
+        self.buffer.as_mut_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsMutSlice2D, AsSlice2D, Strided};
+
+    #[test]
+    fn padded_rows() {
+        let mut grid = Strided::with_stride([1, 2, 3, 0, 4, 5, 6, 0], 2, 3, 4);
+
+        assert_eq!(grid.row(0), &[1, 2, 3]);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.get(1, 2), Some(&6));
+
+        // the padding is not part of the grid
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get(1, 3), None);
+        assert_eq!(grid.get(2, 0), None);
+
+        grid.row_mut(1).copy_from_slice(&[7, 8, 9]);
+        assert_eq!(grid.into_inner(), [1, 2, 3, 0, 7, 8, 9, 0]);
+    }
+
+    #[test]
+    fn last_row_without_padding() {
+        // the last row doesn't need room for its padding
+        let grid = Strided::with_stride([1, 2, 0, 3, 4], 2, 2, 3);
+
+        assert_eq!(grid.row(1), &[3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_small() {
+        Strided::with_stride([0; 7], 2, 3, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn stride_less_than_cols() {
+        Strided::with_stride([0; 8], 2, 4, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid size overflows `usize`")]
+    fn overflow() {
+        Strided::with_stride([0; 8], 3, 1, usize::MAX);
+    }
+
+    #[test]
+    fn no_rows() {
+        let grid = Strided::with_stride([0u8; 0], 0, 3, 4);
+
+        assert_eq!(grid.rows(), 0);
+        assert_eq!(grid.get(0, 0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn row_out_of_bounds() {
+        Strided::new([0; 6], 2, 3).row(2);
+    }
+}
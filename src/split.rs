@@ -0,0 +1,349 @@
+//! Owned split of a buffer into two independently owned halves
+//!
+//! # Example
+//!
+//! ```
+//! use as_slice::split::SplitOwned;
+//! use as_slice::{AsMutSlice, AsSlice};
+//!
+//! let mut buffer = [0u16; 8];
+//!
+//! let (mut head, mut tail) = (&mut buffer).split_owned(3);
+//! head.as_mut_slice().copy_from_slice(&[1; 3]);
+//! tail.as_mut_slice().copy_from_slice(&[2; 5]);
+//!
+//! let buffer = head.join(tail).ok().unwrap();
+//! assert_eq!(buffer, &[1, 1, 1, 2, 2, 2, 2, 2]);
+//! ```
+
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::DerefMut;
+use core::slice;
+
+use stable_deref_trait::StableDeref;
+
+use dma::{ReadBuffer, Word, WriteBuffer};
+use {AsMutSlice, AsSlice, OwnedBuffer};
+
+/// A buffer that can be split into two independently owned halves
+///
+/// This trait is implemented for all the `StableDeref + DerefMut` types whose `Target` is
+/// `AsMutSlice`
+pub trait SplitOwned: StableDeref + DerefMut + Sized
+where
+    Self::Target: AsMutSlice,
+{
+    /// Splits the slice view of the `Deref` target into the elements `[0, mid)` and `[mid, len)`
+    ///
+    /// The halves can be rejoined with `Head::join`. If either half is dropped instead, the
+    /// original buffer is leaked rather than dropped, so the other half remains valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`
+    fn split_owned(self, mid: usize) -> (Head<Self>, Tail<Self>) {
+        let buffer = OwnedBuffer::new(self);
+        let len = buffer.len();
+
+        assert!(mid <= len);
+
+        // NOTE(unsafe) `mid <= len`
+        let ptr = unsafe { buffer.as_mut_ptr().add(mid) };
+
+        (
+            Head {
+                buffer: ManuallyDrop::new(buffer),
+                mid,
+            },
+            Tail {
+                ptr,
+                len: len - mid,
+                _buffer: PhantomData,
+            },
+        )
+    }
+}
+
+impl<B> SplitOwned for B
+where
+    B: StableDeref + DerefMut,
+    B::Target: AsMutSlice,
+{
+}
+
+/// The first half of a split buffer
+///
+/// This half owns the original buffer
+pub struct Head<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    buffer: ManuallyDrop<OwnedBuffer<B>>,
+    mid: usize,
+}
+
+/// The second half of a split buffer
+pub struct Tail<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    ptr: *mut <B::Target as AsSlice>::Element,
+    len: usize,
+    _buffer: PhantomData<B>,
+}
+
+impl<B> Head<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    /// Rejoins the two halves into the original buffer
+    ///
+    /// Returns the halves back if `tail` was not split off the same buffer as `self`
+    pub fn join(self, tail: Tail<B>) -> Result<B, (Head<B>, Tail<B>)> {
+        // NOTE(unsafe) `mid` is within the bounds of `buffer`
+        if unsafe { self.buffer.as_mut_ptr().add(self.mid) } == tail.ptr
+            && self.mid + tail.len == self.buffer.len()
+        {
+            Ok(ManuallyDrop::into_inner(self.buffer).into_inner())
+        } else {
+            Err((self, tail))
+        }
+    }
+}
+
+impl<B> AsSlice for Head<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    type Element = <B::Target as AsSlice>::Element;
+
+    fn as_slice(&self) -> &[<B::Target as AsSlice>::Element] {
+        // NOTE(unsafe) the `Tail` doesn't overlap with `[0, mid)`
+        unsafe { slice::from_raw_parts(self.buffer.as_ptr(), self.mid) }
+    }
+}
+
+impl<B> AsMutSlice for Head<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    fn as_mut_slice(&mut self) -> &mut [<B::Target as AsSlice>::Element] {
+        // NOTE(unsafe) the `Tail` doesn't overlap with `[0, mid)`
+        unsafe { slice::from_raw_parts_mut(self.buffer.as_mut_ptr(), self.mid) }
+    }
+}
+
+impl<B> AsSlice for Tail<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    type Element = <B::Target as AsSlice>::Element;
+
+    fn as_slice(&self) -> &[<B::Target as AsSlice>::Element] {
+        // NOTE(unsafe) the `Head` doesn't overlap with `[mid, len)`; the buffer is never dropped
+        // while this half is alive
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<B> AsMutSlice for Tail<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    fn as_mut_slice(&mut self) -> &mut [<B::Target as AsSlice>::Element] {
+        // NOTE(unsafe) the `Head` doesn't overlap with `[mid, len)`; the buffer is never dropped
+        // while this half is alive
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+// NOTE(unsafe) the halves are leaked, rather than dropped, if they are not rejoined so their memory
+// remains valid and in place as long as `B` is `'static`
+unsafe impl<B> ReadBuffer for Head<B>
+where
+    B: DerefMut + 'static,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Word,
+{
+    type Word = <B::Target as AsSlice>::Element;
+
+    unsafe fn read_buffer(&self) -> (*const <B::Target as AsSlice>::Element, usize) {
+        (self.buffer.as_ptr(), self.mid)
+    }
+}
+
+unsafe impl<B> WriteBuffer for Head<B>
+where
+    B: DerefMut + 'static,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Word,
+{
+    type Word = <B::Target as AsSlice>::Element;
+
+    unsafe fn write_buffer(&mut self) -> (*mut <B::Target as AsSlice>::Element, usize) {
+        (self.buffer.as_mut_ptr(), self.mid)
+    }
+}
+
+unsafe impl<B> ReadBuffer for Tail<B>
+where
+    B: DerefMut + 'static,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Word,
+{
+    type Word = <B::Target as AsSlice>::Element;
+
+    unsafe fn read_buffer(&self) -> (*const <B::Target as AsSlice>::Element, usize) {
+        (self.ptr, self.len)
+    }
+}
+
+unsafe impl<B> WriteBuffer for Tail<B>
+where
+    B: DerefMut + 'static,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Word,
+{
+    type Word = <B::Target as AsSlice>::Element;
+
+    unsafe fn write_buffer(&mut self) -> (*mut <B::Target as AsSlice>::Element, usize) {
+        (self.ptr, self.len)
+    }
+}
+
+// NOTE(unsafe) the `Tail` has exclusive access to its half of the buffer
+unsafe impl<B> Send for Tail<B>
+where
+    B: DerefMut + Send,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Send,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitOwned;
+    use {AsMutSlice, AsSlice};
+
+    fn range<S>(half: &S) -> (usize, usize)
+    where
+        S: AsSlice<Element = u8>,
+    {
+        let slice = half.as_slice();
+        let start = slice.as_ptr() as usize;
+        (start, start + slice.len())
+    }
+
+    #[test]
+    fn halves_are_disjoint() {
+        let mut buffer = [0u8; 8];
+        let (start, end) = (buffer.as_ptr() as usize, buffer.as_ptr() as usize + 8);
+
+        for mid in 0..=8 {
+            let (mut head, mut tail) = (&mut buffer).split_owned(mid);
+
+            let head_range = range(&head);
+            let tail_range = range(&tail);
+            assert_eq!(head_range, (start, start + mid));
+            assert_eq!(tail_range, (start + mid, end));
+
+            head.as_mut_slice().iter_mut().for_each(|x| *x = 1);
+            tail.as_mut_slice().iter_mut().for_each(|x| *x = 2);
+
+            let buffer = head.join(tail).ok().unwrap();
+            assert!(buffer[..mid].iter().all(|x| *x == 1));
+            assert!(buffer[mid..].iter().all(|x| *x == 2));
+        }
+    }
+
+    #[test]
+    fn mid_zero() {
+        let mut buffer = [0u8; 4];
+
+        let (head, tail) = (&mut buffer).split_owned(0);
+        assert!(head.as_slice().is_empty());
+        assert_eq!(tail.as_slice().len(), 4);
+
+        assert!(head.join(tail).is_ok());
+    }
+
+    #[test]
+    fn mid_len() {
+        let mut buffer = [0u8; 4];
+
+        let (head, tail) = (&mut buffer).split_owned(4);
+        assert_eq!(head.as_slice().len(), 4);
+        assert!(tail.as_slice().is_empty());
+
+        assert!(head.join(tail).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mid_out_of_bounds() {
+        let mut buffer = [0u8; 4];
+
+        let _ = (&mut buffer).split_owned(5);
+    }
+
+    #[test]
+    fn join_foreign_tail() {
+        let mut a = [0u8; 4];
+        let mut b = [0u8; 4];
+
+        let (head_a, tail_a) = (&mut a).split_owned(2);
+        let (head_b, tail_b) = (&mut b).split_owned(2);
+
+        let (head_a, tail_b) = match head_a.join(tail_b) {
+            Ok(_) => panic!("joined halves of different buffers"),
+            Err(halves) => halves,
+        };
+
+        assert!(head_a.join(tail_a).is_ok());
+        assert!(head_b.join(tail_b).is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_slice() {
+        use std::boxed::Box;
+
+        let buffer: Box<[u8]> = Box::new([0; 6]);
+
+        let (mut head, mut tail) = buffer.split_owned(2);
+        head.as_mut_slice().copy_from_slice(&[1; 2]);
+        tail.as_mut_slice().copy_from_slice(&[2; 4]);
+
+        let buffer = head.join(tail).ok().unwrap();
+        assert_eq!(&*buffer, &[1, 1, 2, 2, 2, 2]);
+    }
+
+    // the halves must cover the boxed `Deref` target, not the inline `as_mut_slice` view that
+    // moves with the buffer
+    #[test]
+    fn inline_storage() {
+        use owned::tests::Inline;
+
+        let (head, tail) = Inline::new().split_owned(1);
+        let (mut head, mut tail) = moved((head, tail));
+
+        head.as_mut_slice().copy_from_slice(&[1]);
+        tail.as_mut_slice().copy_from_slice(&[2, 3, 4]);
+
+        let buffer = head.join(tail).ok().unwrap();
+        assert_eq!(*buffer.boxed, [1, 2, 3, 4]);
+        assert_eq!(buffer.inline, [0; 4]);
+    }
+
+    fn moved<T>(x: T) -> T {
+        x
+    }
+}
@@ -0,0 +1,254 @@
+//! Volatile access to buffers
+
+use core::ops::DerefMut;
+use core::ptr;
+
+use stable_deref_trait::StableDeref;
+
+use {AsMutSlice, AsSlice, OwnedBuffer};
+
+/// A view of a buffer whose elements are only accessed with volatile reads and writes
+///
+/// The elements are those of the slice view of the buffer's `Deref` target, which doesn't move
+/// when the `VolatileSlice` is moved.
+///
+/// Use this for memory that is shared with something the compiler doesn't know about, e.g. a
+/// coprocessor. Once the buffer has been wrapped no Rust references to its memory are created
+/// until it's released with `into_inner`.
+///
+/// # Example
+///
+/// ```
+/// use as_slice::VolatileSlice;
+///
+/// let mut shared = [0u32; 4];
+///
+/// let mut mailbox = VolatileSlice::new(&mut shared);
+/// mailbox.write(0, 0xdead_beef);
+/// assert_eq!(mailbox.read(0), 0xdead_beef);
+///
+/// let mut snapshot = [0; 4];
+/// mailbox.copy_to_slice(&mut snapshot);
+/// assert_eq!(snapshot, [0xdead_beef, 0, 0, 0]);
+/// ```
+pub struct VolatileSlice<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    buffer: OwnedBuffer<B>,
+}
+
+impl<B> VolatileSlice<B>
+where
+    B: StableDeref + DerefMut,
+    B::Target: AsMutSlice,
+{
+    /// Wraps `buffer`
+    pub fn new(buffer: B) -> Self {
+        VolatileSlice {
+            buffer: OwnedBuffer::new(buffer),
+        }
+    }
+}
+
+impl<B> VolatileSlice<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+    <B::Target as AsSlice>::Element: Copy,
+{
+    /// Returns the number of elements in the buffer
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the buffer has a length of 0
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Performs a volatile read of the element at index `i`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len`
+    pub fn read(&self, i: usize) -> <B::Target as AsSlice>::Element {
+        assert!(i < self.len());
+
+        // NOTE(unsafe) in bounds
+        unsafe { ptr::read_volatile(self.buffer.as_ptr().add(i)) }
+    }
+
+    /// Performs a volatile write of `value` to the element at index `i`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= len`
+    pub fn write(&mut self, i: usize, value: <B::Target as AsSlice>::Element) {
+        assert!(i < self.len());
+
+        // NOTE(unsafe) in bounds
+        unsafe { ptr::write_volatile(self.buffer.as_mut_ptr().add(i), value) }
+    }
+
+    /// Copies all the elements into `dst` using volatile reads
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` and `self` have different lengths
+    pub fn copy_to_slice<S>(&self, dst: &mut S)
+    where
+        S: ?Sized + AsMutSlice<Element = <B::Target as AsSlice>::Element>,
+    {
+        let dst = dst.as_mut_slice();
+
+        assert_eq!(dst.len(), self.len());
+
+        for (i, slot) in dst.iter_mut().enumerate() {
+            *slot = self.read(i);
+        }
+    }
+
+    /// Copies all the elements of `src` into the buffer using volatile writes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `self` have different lengths
+    pub fn copy_from_slice<S>(&mut self, src: &S)
+    where
+        S: ?Sized + AsSlice<Element = <B::Target as AsSlice>::Element>,
+    {
+        let src = src.as_slice();
+
+        assert_eq!(src.len(), self.len());
+
+        for (i, value) in src.iter().enumerate() {
+            self.write(i, *value);
+        }
+    }
+}
+
+impl<B> VolatileSlice<B>
+where
+    B: DerefMut,
+    B::Target: AsMutSlice,
+{
+    /// Releases the underlying buffer
+    pub fn into_inner(self) -> B {
+        self.buffer.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::boxed::Box;
+
+    use super::VolatileSlice;
+
+    #[test]
+    fn static_mut_array() {
+        let buffer: &'static mut [u32; 4] = Box::leak(Box::new([0; 4]));
+
+        let mut slice = VolatileSlice::new(buffer);
+        assert_eq!(slice.len(), 4);
+
+        slice.write(1, 42);
+        assert_eq!(slice.read(1), 42);
+        assert_eq!(slice.read(0), 0);
+
+        slice.copy_from_slice(&[1, 2, 3, 4]);
+        let mut out = [0; 4];
+        slice.copy_to_slice(&mut out);
+        assert_eq!(out, [1, 2, 3, 4]);
+
+        let buffer = slice.into_inner();
+        assert_eq!(buffer, &[1, 2, 3, 4]);
+
+        // NOTE(unsafe) `buffer` came from `Box::leak`
+        drop(unsafe { Box::from_raw(buffer) });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_slice() {
+        let buffer: Box<[u32]> = Box::new([0; 3]);
+
+        let mut slice = VolatileSlice::new(buffer);
+        slice.copy_from_slice(&[7, 8, 9]);
+        slice.write(2, 10);
+        assert_eq!(slice.read(0), 7);
+
+        let mut out = [0; 3];
+        slice.copy_to_slice(&mut out);
+        assert_eq!(out, [7, 8, 10]);
+
+        assert_eq!(&*slice.into_inner(), &[7, 8, 10]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut buffer = [0u32; 0];
+
+        let mut slice = VolatileSlice::new(&mut buffer);
+        assert!(slice.is_empty());
+
+        slice.copy_from_slice(&[]);
+        slice.copy_to_slice(&mut []);
+    }
+
+    #[test]
+    #[should_panic]
+    fn read_out_of_bounds() {
+        let mut buffer = [0u32; 4];
+
+        VolatileSlice::new(&mut buffer).read(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_out_of_bounds() {
+        let mut buffer = [0u32; 4];
+
+        VolatileSlice::new(&mut buffer).write(4, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_to_slice_length_mismatch() {
+        let mut buffer = [0u32; 4];
+
+        VolatileSlice::new(&mut buffer).copy_to_slice(&mut [0; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_slice_length_mismatch() {
+        let mut buffer = [0u32; 4];
+
+        VolatileSlice::new(&mut buffer).copy_from_slice(&[0; 5]);
+    }
+
+    // the elements are those of the boxed `Deref` target, not the inline `as_mut_slice` view that
+    // moves with the buffer
+    #[test]
+    fn inline_storage() {
+        use owned::tests::Inline;
+
+        let mut slice = moved(VolatileSlice::new(Inline::new()));
+        assert_eq!(slice.len(), 4);
+
+        slice.copy_from_slice(&[1, 2, 3, 4]);
+        let mut slice = moved(slice);
+        slice.write(3, 5);
+        assert_eq!(slice.read(0), 1);
+
+        let buffer = slice.into_inner();
+        assert_eq!(*buffer.boxed, [1, 2, 3, 5]);
+        assert_eq!(buffer.inline, [0; 4]);
+    }
+
+    fn moved<T>(x: T) -> T {
+        x
+    }
+}